@@ -0,0 +1,168 @@
+use std::io;
+
+use crate::character::Class;
+use crate::enemy::EnemyKind;
+use crate::event::{CombatAction, GameEvent};
+use crate::profile::Profile;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Requirement {
+    // Slay the foe no later than the given encounter, optionally without drinking a potion all run
+    SlayBy {
+        kind: EnemyKind,
+        encounter: u32,
+        potion_free: bool,
+    },
+    FindItem(&'static str),
+    // Win a battle using nothing but the class special
+    SpecialOnlyVictory(Class),
+    Survive {
+        encounters: u32,
+        class: Option<Class>,
+    },
+}
+
+#[derive(Debug)]
+pub struct Achievement {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub requirement: Requirement,
+}
+
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: "first_blood",
+        title: "First Blood",
+        description: "Win your first battle",
+        requirement: Requirement::Survive {
+            encounters: 1,
+            class: None,
+        },
+    },
+    Achievement {
+        id: "dry_wyrmslayer",
+        title: "Dry-Throated Wyrmslayer",
+        description: "Slay a Dragon at encounter 5 without drinking a potion",
+        requirement: Requirement::SlayBy {
+            kind: EnemyKind::Dragon,
+            encounter: 5,
+            potion_free: true,
+        },
+    },
+    Achievement {
+        id: "relic_hunter",
+        title: "Relic Hunter",
+        description: "Find the Ancient Artifact",
+        requirement: Requirement::FindItem("Ancient Artifact"),
+    },
+    Achievement {
+        id: "pure_flame",
+        title: "Pure Flame",
+        description: "Win a battle with Fireball only",
+        requirement: Requirement::SpecialOnlyVictory(Class::Mage),
+    },
+    Achievement {
+        id: "unbroken_shield",
+        title: "Unbroken Shield",
+        description: "Survive 15 encounters as a Warrior",
+        requirement: Requirement::Survive {
+            encounters: 15,
+            class: Some(Class::Warrior),
+        },
+    },
+    Achievement {
+        id: "long_shadow",
+        title: "The Long Shadow",
+        description: "Survive 25 encounters as a Rogue",
+        requirement: Requirement::Survive {
+            encounters: 25,
+            class: Some(Class::Rogue),
+        },
+    },
+];
+
+// Follows one run's events and unlocks achievements in the player's profile
+pub struct AchievementTracker {
+    class: Class,
+    encounter: u32,
+    potions_used: u32,
+    battle_actions: Vec<CombatAction>,
+    profile: Profile,
+}
+
+impl AchievementTracker {
+    pub fn new(class: Class) -> Self {
+        AchievementTracker {
+            class,
+            encounter: 0,
+            potions_used: 0,
+            battle_actions: Vec::new(),
+            profile: Profile::load().unwrap_or_default(),
+        }
+    }
+
+    // Returns the achievements this event unlocked for the first time
+    pub fn record(&mut self, event: &GameEvent) -> Vec<&'static Achievement> {
+        match event {
+            GameEvent::EncounterStarted(encounter) => {
+                self.encounter = *encounter;
+                self.battle_actions.clear();
+            }
+            GameEvent::CombatActionTaken(action) => self.battle_actions.push(*action),
+            _ if event.is_potion_used() => self.potions_used += 1,
+            _ => {}
+        }
+
+        let unlocked: Vec<&'static Achievement> = ACHIEVEMENTS
+            .iter()
+            .filter(|achievement| !self.profile.has_achievement(achievement.id))
+            .filter(|achievement| self.is_met(achievement.requirement, event))
+            .collect();
+        for achievement in &unlocked {
+            self.profile.unlock_achievement(achievement.id);
+        }
+        unlocked
+    }
+
+    // Merges into the profile on disk so embers and unlocks saved since the run began are kept
+    pub fn save(&self) -> io::Result<()> {
+        let mut profile = Profile::load()?;
+        for achievement in ACHIEVEMENTS {
+            if self.profile.has_achievement(achievement.id) {
+                profile.unlock_achievement(achievement.id);
+            }
+        }
+        profile.save()
+    }
+
+    fn is_met(&self, requirement: Requirement, event: &GameEvent) -> bool {
+        match (requirement, event) {
+            (
+                Requirement::SlayBy {
+                    kind,
+                    encounter,
+                    potion_free,
+                },
+                GameEvent::EnemySlain(slain),
+            ) => {
+                kind == *slain
+                    && self.encounter <= encounter
+                    && (!potion_free || self.potions_used == 0)
+            }
+            (Requirement::FindItem(name), GameEvent::ItemFound(found)) => name == found,
+            (Requirement::SpecialOnlyVictory(class), GameEvent::BattleWon) => {
+                self.class == class
+                    && !self.battle_actions.is_empty()
+                    && self
+                        .battle_actions
+                        .iter()
+                        .all(|action| *action == CombatAction::Special)
+            }
+            (Requirement::Survive { encounters, class }, GameEvent::BattleWon) => {
+                self.encounter >= encounters && class.is_none_or(|class| class == self.class)
+            }
+            _ => false,
+        }
+    }
+}
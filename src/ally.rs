@@ -0,0 +1,247 @@
+use rand::Rng;
+
+use crate::character::{Character, Class, DamageType};
+use crate::rng;
+
+const MAX_ALLIES: usize = 2;
+const PLAYER_THREAT: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllyKind {
+    Wolf,
+    Skeleton,
+    FireElemental,
+}
+
+impl AllyKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AllyKind::Wolf => "Wolf",
+            AllyKind::Skeleton => "Skeleton",
+            AllyKind::FireElemental => "Fire Elemental",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            AllyKind::Wolf => "🐺",
+            AllyKind::Skeleton => "💀",
+            AllyKind::FireElemental => "🔥",
+        }
+    }
+
+    // (base health, base attack); both grow with the player's level
+    fn stats(&self) -> (u32, u32) {
+        match self {
+            AllyKind::Wolf => (40, 8),
+            AllyKind::Skeleton => (60, 5),
+            AllyKind::FireElemental => (30, 12),
+        }
+    }
+
+    fn damage_type(&self) -> DamageType {
+        match self {
+            AllyKind::FireElemental => DamageType::Fire,
+            _ => DamageType::Physical,
+        }
+    }
+
+    // How likely enemies are to strike this ally rather than someone else
+    fn threat(&self) -> u32 {
+        match self {
+            AllyKind::Skeleton => 3, // Stands in front of the player
+            _ => 1,
+        }
+    }
+
+    // Summons fade after this many turns; companions stay until they die
+    fn lifetime(&self) -> Option<u32> {
+        match self {
+            AllyKind::FireElemental => Some(4),
+            _ => None,
+        }
+    }
+
+    // The ally a class can call in battle, and what it costs
+    pub fn summoned_by(class: Class) -> Option<(AllyKind, u32)> {
+        match class {
+            Class::Mage => Some((AllyKind::FireElemental, 25)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Ally {
+    kind: AllyKind,
+    name: String,
+    health: u32,
+    max_health: u32,
+    attack: u32,
+    turns_left: Option<u32>,
+}
+
+impl Ally {
+    pub fn new(kind: AllyKind, level: u32) -> Self {
+        let (health, attack) = kind.stats();
+        let max_health = health + level * 5;
+        Ally {
+            kind,
+            name: kind.name().to_string(),
+            health: max_health,
+            max_health,
+            attack: attack + level * 2,
+            turns_left: kind.lifetime(),
+        }
+    }
+
+    pub fn kind(&self) -> AllyKind {
+        self.kind
+    }
+
+    pub fn damage_type(&self) -> DamageType {
+        self.kind.damage_type()
+    }
+
+    // Simple AI: wolves sometimes lunge for the throat, skeletons hack away
+    // and elementals burn hotter as they gutter out
+    pub fn strike(&self) -> (u32, &'static str) {
+        let mut rng = rng::rng();
+        match self.kind {
+            AllyKind::Wolf if rng.random_range(1..=100) <= 25 => {
+                (self.attack * 2, "lunges for the throat")
+            }
+            AllyKind::Wolf => (self.attack, "bites"),
+            AllyKind::Skeleton => (self.attack, "hacks with a rusted blade"),
+            AllyKind::FireElemental => {
+                let fading = self.turns_left == Some(1);
+                let damage = if fading { self.attack * 2 } else { self.attack };
+                (damage, if fading { "flares" } else { "scorches" })
+            }
+        }
+    }
+
+    fn fade(&mut self) {
+        if let Some(turns) = self.turns_left.as_mut() {
+            *turns = turns.saturating_sub(1);
+        }
+    }
+}
+
+impl Character for Ally {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_health(&self) -> u32 {
+        self.health
+    }
+
+    fn get_max_health(&self) -> u32 {
+        self.max_health
+    }
+
+    fn get_level(&self) -> u32 {
+        1
+    }
+
+    fn set_health(&mut self, health: u32) {
+        self.health = health;
+    }
+
+    fn get_experience(&self) -> u32 {
+        0
+    }
+
+    fn set_experience(&mut self, _experience: u32) {
+        // Allies don't level up
+    }
+
+    fn level_up(&mut self) {
+        // Allies don't level up
+    }
+}
+
+// The allies fighting beside the player
+#[derive(Debug, Default)]
+pub struct Party {
+    allies: Vec<Ally>,
+}
+
+impl Party {
+    pub fn new() -> Self {
+        Party::default()
+    }
+
+    pub fn allies(&self) -> &[Ally] {
+        &self.allies
+    }
+
+    pub fn allies_mut(&mut self) -> &mut [Ally] {
+        &mut self.allies
+    }
+
+    // Only one of each kind may follow the player at a time
+    pub fn has_room_for(&self, kind: AllyKind) -> bool {
+        self.allies.len() < MAX_ALLIES && !self.allies.iter().any(|ally| ally.kind == kind)
+    }
+
+    // Returns false if there is no room for another ally
+    pub fn recruit(&mut self, kind: AllyKind, level: u32) -> bool {
+        if !self.has_room_for(kind) {
+            return false;
+        }
+        self.allies.push(Ally::new(kind, level));
+        true
+    }
+
+    // Picks who an enemy strike lands on: None for the player, or an ally's index
+    pub fn pick_target(&self) -> Option<usize> {
+        let total: u32 = PLAYER_THREAT + self.allies.iter().map(|a| a.kind.threat()).sum::<u32>();
+        let mut roll = rng::rng().random_range(1..=total);
+        if roll <= PLAYER_THREAT {
+            return None;
+        }
+        roll -= PLAYER_THREAT;
+        for (i, ally) in self.allies.iter().enumerate() {
+            if roll <= ally.kind.threat() {
+                return Some(i);
+            }
+            roll -= ally.kind.threat();
+        }
+        None
+    }
+
+    // Removes fallen allies for good and returns them
+    pub fn bury_fallen(&mut self) -> Vec<Ally> {
+        let (fallen, living) = self.allies.drain(..).partition(|ally| !ally.is_alive());
+        self.allies = living;
+        fallen
+    }
+
+    // Counts down summons after their turn and returns the ones that faded
+    pub fn tick_summons(&mut self) -> Vec<Ally> {
+        for ally in &mut self.allies {
+            ally.fade();
+        }
+        let (faded, lasting) = self
+            .allies
+            .drain(..)
+            .partition(|ally| ally.turns_left == Some(0));
+        self.allies = lasting;
+        faded
+    }
+
+    // Summons vanish when a battle ends; companions lick their wounds
+    pub fn end_battle(&mut self) -> Vec<Ally> {
+        let (faded, lasting) = self
+            .allies
+            .drain(..)
+            .partition(|ally| ally.turns_left.is_some());
+        self.allies = lasting;
+        for ally in &mut self.allies {
+            ally.health = (ally.health + ally.max_health / 4).min(ally.max_health);
+        }
+        faded
+    }
+}
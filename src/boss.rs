@@ -0,0 +1,303 @@
+use crate::character::{Character, Combat, DamageType};
+use crate::enemy::{Enemy, EnemyKind};
+
+#[derive(Debug, Clone, Copy)]
+pub enum AttackPattern {
+    Claws,
+    Breath {
+        every: u32,
+        multiplier: f32,
+        damage_type: DamageType,
+        flavor: &'static str,
+    },
+    Flurry {
+        hits: u32,
+        multiplier: f32,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PhaseEffect {
+    Pattern(AttackPattern),
+    Summon { kind: EnemyKind, count: u32 },
+    Enrage { bonus_attack: u32 },
+}
+
+#[derive(Debug)]
+pub struct BossPhase {
+    name: &'static str,
+    health_threshold: u32, // Phase begins once the boss drops to this % of max HP
+    lines: &'static [&'static str],
+    effects: &'static [PhaseEffect],
+}
+
+// A single hit the enemy side lands on the player during its turn
+#[derive(Debug, Clone)]
+pub struct Strike {
+    pub attacker: String,
+    pub flavor: String,
+    pub damage: u32,
+    pub damage_type: DamageType,
+}
+
+impl Strike {
+    pub fn basic(enemy: &Enemy) -> Self {
+        Strike {
+            attacker: enemy.get_name().to_string(),
+            flavor: enemy.attack_flavor().to_string(),
+            damage: enemy.attack(),
+            damage_type: enemy.attack_damage_type(),
+        }
+    }
+}
+
+const ASHEN_WYRM_PHASES: &[BossPhase] = &[
+    BossPhase {
+        name: "Smoldering Wrath",
+        health_threshold: 100,
+        lines: &["Vorthax uncoils from its hoard, embers drifting from its nostrils."],
+        effects: &[PhaseEffect::Pattern(AttackPattern::Claws)],
+    },
+    BossPhase {
+        name: "Wings of Cinder",
+        health_threshold: 66,
+        lines: &[
+            "Vorthax takes to the air, and the cavern fills with choking ash.",
+            "Shrill cries answer from the tunnels - its brood is coming!",
+        ],
+        effects: &[
+            PhaseEffect::Pattern(AttackPattern::Breath {
+                every: 2,
+                multiplier: 1.8,
+                damage_type: DamageType::Fire,
+                flavor: "bathes the cavern in a torrent of flame",
+            }),
+            PhaseEffect::Summon {
+                kind: EnemyKind::DragonWhelp,
+                count: 2,
+            },
+        ],
+    },
+    BossPhase {
+        name: "The Last Inferno",
+        health_threshold: 30,
+        lines: &[
+            "Molten blood pours from Vorthax's wounds. It crashes down, mad with fury.",
+            "There is no retreat now - only the end of one of you.",
+        ],
+        effects: &[
+            PhaseEffect::Enrage { bonus_attack: 6 },
+            PhaseEffect::Pattern(AttackPattern::Flurry {
+                hits: 2,
+                multiplier: 0.75,
+            }),
+        ],
+    },
+];
+
+const GLOOM_WYRM_PHASES: &[BossPhase] = &[
+    BossPhase {
+        name: "Veil of Night",
+        health_threshold: 100,
+        lines: &["Nyxara's scales drink the torchlight. Only her eyes remain visible."],
+        effects: &[PhaseEffect::Pattern(AttackPattern::Claws)],
+    },
+    BossPhase {
+        name: "Gloomcall",
+        health_threshold: 60,
+        lines: &["Nyxara lets out a soundless scream. The dead of the lair rise to her call."],
+        effects: &[
+            PhaseEffect::Summon {
+                kind: EnemyKind::Specter,
+                count: 2,
+            },
+            PhaseEffect::Pattern(AttackPattern::Breath {
+                every: 3,
+                multiplier: 2.0,
+                damage_type: DamageType::Shadow,
+                flavor: "exhales a tide of living darkness",
+            }),
+        ],
+    },
+    BossPhase {
+        name: "Eclipse",
+        health_threshold: 25,
+        lines: &["The lair goes utterly black. Nyxara's breath comes faster, hungrier."],
+        effects: &[
+            PhaseEffect::Enrage { bonus_attack: 8 },
+            PhaseEffect::Pattern(AttackPattern::Breath {
+                every: 2,
+                multiplier: 2.0,
+                damage_type: DamageType::Shadow,
+                flavor: "exhales a tide of living darkness",
+            }),
+        ],
+    },
+];
+
+#[derive(Debug)]
+pub struct Boss {
+    title: &'static str,
+    phases: &'static [BossPhase],
+    phase: usize,
+    pattern: AttackPattern,
+    turn: u32,
+    adds: Vec<Enemy>,
+    loot: &'static [(&'static str, u32)],
+}
+
+impl Boss {
+    // Lair visits alternate between the known wyrms, each one stronger than the last
+    pub fn for_lair(visit: u32) -> (Self, Enemy) {
+        let level = visit + 2;
+        if visit % 2 == 1 {
+            let boss = Boss::new(
+                "Vorthax the Ashen Wyrm",
+                ASHEN_WYRM_PHASES,
+                &[("Dragon Scale", 250), ("Ember Heart", 400)],
+            );
+            let enemy = Enemy::new(EnemyKind::Dragon, level).empowered(boss.title, 30, 0);
+            (boss, enemy)
+        } else {
+            let boss = Boss::new(
+                "Nyxara the Gloom Wyrm",
+                GLOOM_WYRM_PHASES,
+                &[("Dragon Scale", 250), ("Eclipse Pearl", 450)],
+            );
+            let enemy = Enemy::new(EnemyKind::Dragon, level).empowered(boss.title, 40, 3);
+            (boss, enemy)
+        }
+    }
+
+    fn new(
+        title: &'static str,
+        phases: &'static [BossPhase],
+        loot: &'static [(&'static str, u32)],
+    ) -> Self {
+        Boss {
+            title,
+            phases,
+            phase: 0,
+            pattern: AttackPattern::Claws,
+            turn: 0,
+            adds: Vec::new(),
+            loot,
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        self.title
+    }
+
+    pub fn phase_name(&self) -> &str {
+        self.phases[self.phase].name
+    }
+
+    pub fn loot(&self) -> &'static [(&'static str, u32)] {
+        self.loot
+    }
+
+    pub fn adds(&self) -> &[Enemy] {
+        &self.adds
+    }
+
+    pub fn adds_mut(&mut self) -> &mut [Enemy] {
+        &mut self.adds
+    }
+
+    // Summoned adds shield the boss: strikes land on them first
+    pub fn front_add_mut(&mut self) -> Option<&mut Enemy> {
+        self.adds.first_mut()
+    }
+
+    pub fn clear_fallen_adds(&mut self) {
+        self.adds.retain(|add| add.is_alive());
+    }
+
+    // Opening phase - applied once when the fight begins
+    pub fn begin(&mut self, enemy: &mut Enemy) {
+        self.enter_phase(0, enemy);
+    }
+
+    // Moves through every phase whose HP threshold has been crossed
+    pub fn check_phase(&mut self, enemy: &mut Enemy) {
+        let health_percent = enemy.get_health() * 100 / enemy.get_max_health();
+        while self.phase + 1 < self.phases.len()
+            && health_percent <= self.phases[self.phase + 1].health_threshold
+        {
+            self.enter_phase(self.phase + 1, enemy);
+        }
+    }
+
+    fn enter_phase(&mut self, index: usize, enemy: &mut Enemy) {
+        self.phase = index;
+        let phase = &self.phases[index];
+
+        println!("\n\x1b[95m╔════════════════════════════════════════╗");
+        println!("║  PHASE {}: {:<29}║", index + 1, phase.name);
+        println!("╚════════════════════════════════════════╝\x1b[0m");
+        for line in phase.lines {
+            println!("\x1b[35m{}\x1b[0m", line);
+        }
+
+        for effect in phase.effects {
+            match *effect {
+                PhaseEffect::Pattern(pattern) => {
+                    self.pattern = pattern;
+                    self.turn = 0;
+                }
+                PhaseEffect::Summon { kind, count } => {
+                    for _ in 0..count {
+                        self.adds
+                            .push(Enemy::new(kind, enemy.get_level()).into_minion());
+                    }
+                    println!(
+                        "\x1b[91m👥 {} {}(s) join the fight!\x1b[0m",
+                        count,
+                        kind.name()
+                    );
+                }
+                PhaseEffect::Enrage { bonus_attack } => {
+                    enemy.enrage(bonus_attack);
+                    println!(
+                        "\x1b[91m💢 {} becomes ENRAGED! (+{} attack)\x1b[0m",
+                        enemy.get_name(),
+                        bonus_attack
+                    );
+                }
+            }
+        }
+    }
+
+    // The boss's own attacks this turn, according to the active phase's pattern
+    pub fn strikes(&mut self, enemy: &Enemy) -> Vec<Strike> {
+        self.turn += 1;
+        match self.pattern {
+            AttackPattern::Claws => vec![Strike::basic(enemy)],
+            AttackPattern::Breath {
+                every,
+                multiplier,
+                damage_type,
+                flavor,
+            } => {
+                if self.turn.is_multiple_of(every) {
+                    vec![Strike {
+                        attacker: enemy.get_name().to_string(),
+                        flavor: flavor.to_string(),
+                        damage: (enemy.attack() as f32 * multiplier) as u32,
+                        damage_type,
+                    }]
+                } else {
+                    vec![Strike::basic(enemy)]
+                }
+            }
+            AttackPattern::Flurry { hits, multiplier } => (0..hits)
+                .map(|_| {
+                    let mut strike = Strike::basic(enemy);
+                    strike.damage = (strike.damage as f32 * multiplier) as u32;
+                    strike
+                })
+                .collect(),
+        }
+    }
+}
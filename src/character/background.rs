@@ -0,0 +1,51 @@
+use super::stat::Stat;
+
+// Where the hero came from, and what they carried out of it
+#[derive(Debug)]
+pub struct Background {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub gold: u32,
+    pub items: &'static [(&'static str, u32)],
+    pub bonus: Option<(Stat, u32)>,
+}
+
+pub const BACKGROUNDS: &[Background] = &[
+    Background {
+        name: "Noble",
+        description: "Born to coin and comfort",
+        gold: 100,
+        items: &[],
+        bonus: None,
+    },
+    Background {
+        name: "Outcast",
+        description: "Hardened by years on the road",
+        gold: 0,
+        items: &[("Fire Flask", 70), ("Purifying Salts", 60)],
+        bonus: Some((Stat::Vitality, 2)),
+    },
+    Background {
+        name: "Scholar",
+        description: "Left the archives with a book",
+        gold: 20,
+        items: &[("Mystic Tome", 220), ("Energy Potion", 40)],
+        bonus: None,
+    },
+];
+
+impl Background {
+    pub fn describe(&self) -> String {
+        let mut perks = Vec::new();
+        if self.gold > 0 {
+            perks.push(format!("+{} gold", self.gold));
+        }
+        for (name, _) in self.items {
+            perks.push(name.to_string());
+        }
+        if let Some((stat, points)) = self.bonus {
+            perks.push(format!("+{} {}", points, stat.name()));
+        }
+        perks.join(", ")
+    }
+}
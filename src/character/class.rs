@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use super::stat::Stat;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Class {
+    Warrior,
+    Mage,
+    Rogue,
+    Cleric,
+    Ranger,
+    Necromancer,
+}
+
+impl Class {
+    pub const ALL: [Class; 6] = [
+        Class::Warrior,
+        Class::Mage,
+        Class::Rogue,
+        Class::Cleric,
+        Class::Ranger,
+        Class::Necromancer,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Class::Warrior => "Warrior",
+            Class::Mage => "Mage",
+            Class::Rogue => "Rogue",
+            Class::Cleric => "Cleric",
+            Class::Ranger => "Ranger",
+            Class::Necromancer => "Necromancer",
+        }
+    }
+
+    // The stats this class can train at character creation
+    pub fn stats(&self) -> &'static [Stat] {
+        match self {
+            Class::Warrior => &[Stat::Strength, Stat::Armor, Stat::Vitality],
+            Class::Mage => &[Stat::Intelligence, Stat::Vitality],
+            Class::Rogue => &[Stat::Agility, Stat::Stealth, Stat::Vitality],
+            Class::Cleric => &[Stat::Wisdom, Stat::Vitality],
+            Class::Ranger => &[Stat::Agility, Stat::Vitality],
+            Class::Necromancer => &[Stat::Intelligence, Stat::Vitality],
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use super::class::Class;
+use super::damage::{Affinity, DamageType};
+use super::effects::StatusEffect;
+use super::stat::Stat;
+use super::talent::{TalentBonus, Talents};
+use super::traits::{Character, Combat, Fighter, ResourcePool};
+
+const BASE_HEALTH: u32 = 90;
+const HEALTH_PER_LEVEL: u32 = 8;
+const BASE_FAITH: u32 = 60;
+const FAITH_PER_LEVEL: u32 = 5;
+const BASE_WISDOM: u32 = 16;
+const SMITE_COST: u32 = 25;
+const SMITE_DAMAGE_MULT: u32 = 2;
+const DEFEND_FAITH_GAIN: u32 = 15;
+
+#[derive(Debug, Clone)]
+pub struct Cleric {
+    name: String,
+    health: u32,
+    max_health: u32,
+    level: u32,
+    experience: u32,
+    effects: Vec<StatusEffect>,
+    talents: Talents,
+    wisdom: u32,
+    faith: u32,
+    max_faith: u32,
+}
+
+impl Cleric {
+    pub fn new(name: String, level: u32) -> Self {
+        let max_health = BASE_HEALTH + (level * HEALTH_PER_LEVEL);
+        let max_faith = BASE_FAITH + (level * FAITH_PER_LEVEL);
+        Cleric {
+            name,
+            health: max_health,
+            max_health,
+            level,
+            experience: 0,
+            effects: Vec::new(),
+            talents: Talents::new(),
+            wisdom: BASE_WISDOM + level,
+            faith: max_faith,
+            max_faith,
+        }
+    }
+}
+
+impl Character for Cleric {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_health(&self) -> u32 {
+        self.health
+    }
+
+    fn get_max_health(&self) -> u32 {
+        self.max_health
+    }
+
+    fn get_level(&self) -> u32 {
+        self.level
+    }
+
+    fn set_health(&mut self, health: u32) {
+        self.health = health;
+    }
+
+    fn get_experience(&self) -> u32 {
+        self.experience
+    }
+
+    fn set_experience(&mut self, experience: u32) {
+        self.experience = experience;
+    }
+
+    fn level_up(&mut self) {
+        self.level += 1;
+        self.max_health += HEALTH_PER_LEVEL;
+        self.health += HEALTH_PER_LEVEL;
+        self.wisdom += 1;
+        self.max_faith = BASE_FAITH + (self.level * FAITH_PER_LEVEL);
+    }
+}
+
+impl ResourcePool for Cleric {
+    fn get_resource(&self) -> u32 {
+        self.faith
+    }
+
+    fn get_max_resource(&self) -> u32 {
+        self.max_faith
+    }
+
+    fn get_resource_name(&self) -> &str {
+        "Faith"
+    }
+
+    fn set_resource(&mut self, amount: u32) {
+        self.faith = amount.min(self.max_faith);
+    }
+}
+
+impl Combat for Cleric {
+    fn attack(&self) -> u32 {
+        self.wisdom + 8 + self.talents.total(TalentBonus::Attack)
+    }
+
+    // Smite sears the foe with holy light and mends the cleric's wounds
+    fn special_ability(&mut self) -> Option<u32> {
+        if !self.consume_resource(self.special_ability_cost()) {
+            println!("❌ {} doesn't have enough Faith!", self.name);
+            return None;
+        }
+
+        let damage = self.special_damage();
+        println!(
+            "✨ {} calls down Smite for {} damage! Faith: {}/{}",
+            self.name, damage, self.faith, self.max_faith
+        );
+        if self.health < self.max_health {
+            self.heal(self.wisdom);
+        }
+        Some(damage)
+    }
+
+    fn special_damage(&self) -> u32 {
+        self.talents.special_damage(self.wisdom, SMITE_DAMAGE_MULT) + 10
+    }
+
+    fn special_ability_cost(&self) -> u32 {
+        self.talents.special_cost(SMITE_COST)
+    }
+
+    fn special_ability_name(&self) -> &str {
+        "Smite"
+    }
+
+    fn can_use_special(&self) -> bool {
+        self.has_resource(self.special_ability_cost())
+    }
+
+    fn defend_resource_gain(&self) -> u32 {
+        DEFEND_FAITH_GAIN + self.talents.total(TalentBonus::DefendGain)
+    }
+
+    fn attack_damage_type(&self) -> DamageType {
+        DamageType::Physical
+    }
+
+    fn special_damage_type(&self) -> DamageType {
+        DamageType::Holy
+    }
+
+    fn affinity(&self, damage_type: DamageType) -> Affinity {
+        match damage_type {
+            DamageType::Holy | DamageType::Shadow => Affinity::Resistant,
+            DamageType::Fire => Affinity::Weak,
+            _ => Affinity::Neutral,
+        }
+    }
+}
+
+impl Fighter for Cleric {
+    fn class(&self) -> Class {
+        Class::Cleric
+    }
+
+    fn effects(&self) -> &[StatusEffect] {
+        &self.effects
+    }
+
+    fn effects_mut(&mut self) -> &mut Vec<StatusEffect> {
+        &mut self.effects
+    }
+
+    fn talents(&self) -> &Talents {
+        &self.talents
+    }
+
+    fn talents_mut(&mut self) -> &mut Talents {
+        &mut self.talents
+    }
+
+    fn stat(&self, stat: Stat) -> u32 {
+        match stat {
+            Stat::Wisdom => self.wisdom,
+            Stat::Vitality => self.max_health,
+            _ => 0,
+        }
+    }
+
+    fn raise_stat(&mut self, stat: Stat, points: u32) {
+        match stat {
+            Stat::Wisdom => self.wisdom += points * stat.per_point(),
+            Stat::Vitality => {
+                let gain = points * stat.per_point();
+                self.max_health += gain;
+                self.health += gain;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,64 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageType {
+    Physical,
+    Fire,
+    Frost,
+    Shadow,
+    Holy,
+}
+
+impl DamageType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DamageType::Physical => "Physical",
+            DamageType::Fire => "Fire",
+            DamageType::Frost => "Frost",
+            DamageType::Shadow => "Shadow",
+            DamageType::Holy => "Holy",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            DamageType::Physical => "🗡️",
+            DamageType::Fire => "🔥",
+            DamageType::Frost => "❄️",
+            DamageType::Shadow => "🌑",
+            DamageType::Holy => "✨",
+        }
+    }
+}
+
+// How a target reacts to a given damage type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Affinity {
+    Weak,
+    Neutral,
+    Resistant,
+}
+
+impl Affinity {
+    pub fn apply(&self, damage: u32) -> u32 {
+        match self {
+            Affinity::Weak => damage * 3 / 2,
+            Affinity::Neutral => damage,
+            Affinity::Resistant => damage / 2,
+        }
+    }
+
+    pub fn describe(&self, target: &str, damage_type: DamageType) -> Option<String> {
+        match self {
+            Affinity::Weak => Some(format!(
+                "\x1b[92m🎯 It's super effective! {} is weak to {}.\x1b[0m",
+                target,
+                damage_type.name()
+            )),
+            Affinity::Neutral => None,
+            Affinity::Resistant => Some(format!(
+                "\x1b[90m🛡️  Not very effective... {} resists {}.\x1b[0m",
+                target,
+                damage_type.name()
+            )),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectKind {
+    Invigorated,
+    Weakened,
+    Warded,
+    Cursed,
+    Armored,
+    Poisoned,
+}
+
+impl EffectKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EffectKind::Invigorated => "Invigorated",
+            EffectKind::Weakened => "Weakened",
+            EffectKind::Warded => "Warded",
+            EffectKind::Cursed => "Cursed",
+            EffectKind::Armored => "Armored",
+            EffectKind::Poisoned => "Poisoned",
+        }
+    }
+
+    pub fn is_harmful(&self) -> bool {
+        matches!(
+            self,
+            EffectKind::Weakened | EffectKind::Cursed | EffectKind::Poisoned
+        )
+    }
+
+    // Counted down every turn of a battle instead of every battle, and gone once it ends
+    pub fn per_turn(&self) -> bool {
+        matches!(self, EffectKind::Poisoned)
+    }
+
+    pub fn duration_unit(&self) -> &'static str {
+        if self.per_turn() {
+            "turn(s)"
+        } else {
+            "battle(s)"
+        }
+    }
+
+    pub fn attack_modifier(&self) -> f32 {
+        match self {
+            EffectKind::Invigorated => 1.25,
+            EffectKind::Weakened => 0.75,
+            _ => 1.0,
+        }
+    }
+
+    pub fn damage_taken_modifier(&self) -> f32 {
+        match self {
+            EffectKind::Warded => 0.75,
+            EffectKind::Cursed => 1.25,
+            EffectKind::Armored => 0.8,
+            _ => 1.0,
+        }
+    }
+}
+
+// A buff or debuff that lasts for a number of battles
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatusEffect {
+    pub kind: EffectKind,
+    pub battles: u32, // Turns instead for effects counted per turn
+}
+
+impl StatusEffect {
+    pub fn new(kind: EffectKind, battles: u32) -> Self {
+        StatusEffect { kind, battles }
+    }
+}
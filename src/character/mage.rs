@@ -1,3 +1,8 @@
+use super::class::Class;
+use super::damage::{Affinity, DamageType};
+use super::effects::StatusEffect;
+use super::stat::Stat;
+use super::talent::{TalentBonus, Talents};
 use super::traits::{Character, Combat, Fighter, ResourcePool};
 
 const BASE_HEALTH: u32 = 60;
@@ -7,6 +12,7 @@ const MANA_PER_LEVEL: u32 = 10;
 const BASE_INTELLIGENCE: u32 = 20;
 const FIREBALL_COST: u32 = 30;
 const FIREBALL_DAMAGE_MULT: u32 = 3;
+const DEFEND_MANA_GAIN: u32 = 10;
 
 #[derive(Debug, Clone)]
 pub struct Mage {
@@ -14,6 +20,9 @@ pub struct Mage {
     health: u32,
     max_health: u32,
     level: u32,
+    experience: u32,
+    effects: Vec<StatusEffect>,
+    talents: Talents,
     intelligence: u32,
     mana: u32,
     max_mana: u32,
@@ -28,6 +37,9 @@ impl Mage {
             health: max_health,
             max_health,
             level,
+            experience: 0,
+            effects: Vec::new(),
+            talents: Talents::new(),
             intelligence: BASE_INTELLIGENCE + level,
             mana: max_mana,
             max_mana,
@@ -55,6 +67,22 @@ impl Character for Mage {
     fn set_health(&mut self, health: u32) {
         self.health = health;
     }
+
+    fn get_experience(&self) -> u32 {
+        self.experience
+    }
+
+    fn set_experience(&mut self, experience: u32) {
+        self.experience = experience;
+    }
+
+    fn level_up(&mut self) {
+        self.level += 1;
+        self.max_health += HEALTH_PER_LEVEL;
+        self.health += HEALTH_PER_LEVEL;
+        self.intelligence += 1;
+        self.max_mana = BASE_MANA + (self.level * MANA_PER_LEVEL);
+    }
 }
 
 impl ResourcePool for Mage {
@@ -77,16 +105,16 @@ impl ResourcePool for Mage {
 
 impl Combat for Mage {
     fn attack(&self) -> u32 {
-        self.intelligence * 2
+        self.intelligence * 2 + self.talents.total(TalentBonus::Attack)
     }
 
     fn special_ability(&mut self) -> Option<u32> {
-        if !self.consume_resource(FIREBALL_COST) {
+        if !self.consume_resource(self.special_ability_cost()) {
             println!("❌ {} doesn't have enough Mana!", self.name);
             return None;
         }
 
-        let damage = self.intelligence * FIREBALL_DAMAGE_MULT;
+        let damage = self.special_damage();
         println!(
             "🔥 {} casts Fireball for {} damage! Mana: {}/{}",
             self.name, damage, self.mana, self.max_mana
@@ -94,8 +122,13 @@ impl Combat for Mage {
         Some(damage)
     }
 
+    fn special_damage(&self) -> u32 {
+        self.talents
+            .special_damage(self.intelligence, FIREBALL_DAMAGE_MULT)
+    }
+
     fn special_ability_cost(&self) -> u32 {
-        FIREBALL_COST
+        self.talents.special_cost(FIREBALL_COST)
     }
 
     fn special_ability_name(&self) -> &str {
@@ -103,8 +136,68 @@ impl Combat for Mage {
     }
 
     fn can_use_special(&self) -> bool {
-        self.has_resource(FIREBALL_COST)
+        self.has_resource(self.special_ability_cost())
+    }
+
+    fn defend_resource_gain(&self) -> u32 {
+        DEFEND_MANA_GAIN + self.talents.total(TalentBonus::DefendGain)
+    }
+
+    fn attack_damage_type(&self) -> DamageType {
+        DamageType::Frost
+    }
+
+    fn special_damage_type(&self) -> DamageType {
+        DamageType::Fire
+    }
+
+    fn affinity(&self, damage_type: DamageType) -> Affinity {
+        match damage_type {
+            DamageType::Fire | DamageType::Frost => Affinity::Resistant,
+            DamageType::Physical => Affinity::Weak,
+            _ => Affinity::Neutral,
+        }
     }
 }
 
-impl Fighter for Mage {}
+impl Fighter for Mage {
+    fn class(&self) -> Class {
+        Class::Mage
+    }
+
+    fn effects(&self) -> &[StatusEffect] {
+        &self.effects
+    }
+
+    fn effects_mut(&mut self) -> &mut Vec<StatusEffect> {
+        &mut self.effects
+    }
+
+    fn talents(&self) -> &Talents {
+        &self.talents
+    }
+
+    fn talents_mut(&mut self) -> &mut Talents {
+        &mut self.talents
+    }
+
+    fn stat(&self, stat: Stat) -> u32 {
+        match stat {
+            Stat::Intelligence => self.intelligence,
+            Stat::Vitality => self.max_health,
+            _ => 0,
+        }
+    }
+
+    fn raise_stat(&mut self, stat: Stat, points: u32) {
+        match stat {
+            Stat::Intelligence => self.intelligence += points * stat.per_point(),
+            Stat::Vitality => {
+                let gain = points * stat.per_point();
+                self.max_health += gain;
+                self.health += gain;
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,9 +1,27 @@
+pub mod background;
+pub mod class;
+pub mod cleric;
+pub mod damage;
+pub mod effects;
 pub mod mage;
+pub mod necromancer;
+pub mod ranger;
 pub mod rogue;
+pub mod stat;
+pub mod talent;
 pub mod traits;
 pub mod warrior;
 
+pub use background::{Background, BACKGROUNDS};
+pub use class::Class;
+pub use cleric::Cleric;
+pub use damage::DamageType;
+pub use effects::{EffectKind, StatusEffect};
 pub use mage::Mage;
+pub use necromancer::Necromancer;
+pub use ranger::Ranger;
 pub use rogue::Rogue;
+pub use stat::Stat;
+pub use talent::Talent;
 pub use traits::{Character, Combat, Fighter};
 pub use warrior::Warrior;
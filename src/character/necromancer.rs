@@ -0,0 +1,226 @@
+use super::class::Class;
+use super::damage::{Affinity, DamageType};
+use super::effects::StatusEffect;
+use super::stat::Stat;
+use super::talent::{TalentBonus, Talents};
+use super::traits::{Character, Combat, Fighter, ResourcePool};
+
+const BASE_HEALTH: u32 = 65;
+const HEALTH_PER_LEVEL: u32 = 5;
+const BASE_INTELLIGENCE: u32 = 18;
+const BASE_SOULS: u32 = 4;
+const STARTING_SOULS: u32 = 2;
+const SOULS_PER_KILL: u32 = 1;
+const SOULS_PER_RESTORE: u32 = 1;
+const SOUL_REND_COST: u32 = 2;
+const SOUL_REND_DAMAGE_MULT: u32 = 3;
+
+#[derive(Debug, Clone)]
+pub struct Necromancer {
+    name: String,
+    health: u32,
+    max_health: u32,
+    level: u32,
+    experience: u32,
+    effects: Vec<StatusEffect>,
+    talents: Talents,
+    intelligence: u32,
+    souls: u32,
+    max_souls: u32,
+}
+
+impl Necromancer {
+    pub fn new(name: String, level: u32) -> Self {
+        let max_health = BASE_HEALTH + (level * HEALTH_PER_LEVEL);
+        Necromancer {
+            name,
+            health: max_health,
+            max_health,
+            level,
+            experience: 0,
+            effects: Vec::new(),
+            talents: Talents::new(),
+            intelligence: BASE_INTELLIGENCE + level,
+            souls: STARTING_SOULS,
+            max_souls: BASE_SOULS + level,
+        }
+    }
+}
+
+impl Character for Necromancer {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_health(&self) -> u32 {
+        self.health
+    }
+
+    fn get_max_health(&self) -> u32 {
+        self.max_health
+    }
+
+    fn get_level(&self) -> u32 {
+        self.level
+    }
+
+    fn set_health(&mut self, health: u32) {
+        self.health = health;
+    }
+
+    fn get_experience(&self) -> u32 {
+        self.experience
+    }
+
+    fn set_experience(&mut self, experience: u32) {
+        self.experience = experience;
+    }
+
+    fn level_up(&mut self) {
+        self.level += 1;
+        self.max_health += HEALTH_PER_LEVEL;
+        self.health += HEALTH_PER_LEVEL;
+        self.intelligence += 1;
+        self.max_souls = BASE_SOULS + self.level;
+    }
+}
+
+impl ResourcePool for Necromancer {
+    fn get_resource(&self) -> u32 {
+        self.souls
+    }
+
+    fn get_max_resource(&self) -> u32 {
+        self.max_souls + self.talents.total(TalentBonus::MaxResource)
+    }
+
+    fn get_resource_name(&self) -> &str {
+        "Souls"
+    }
+
+    fn set_resource(&mut self, amount: u32) {
+        self.souls = amount.min(self.get_max_resource());
+    }
+
+    // Potions and runes can stir at most a single soul; the rest must be reaped
+    fn restore_resource(&mut self, amount: u32) -> u32 {
+        let before = self.souls;
+        self.set_resource(before + amount.min(SOULS_PER_RESTORE));
+        self.souls - before
+    }
+}
+
+impl Combat for Necromancer {
+    fn attack(&self) -> u32 {
+        self.intelligence + 6 + self.talents.total(TalentBonus::Attack)
+    }
+
+    fn special_ability(&mut self) -> Option<u32> {
+        if !self.consume_resource(self.special_ability_cost()) {
+            println!("❌ {} doesn't have enough Souls!", self.name);
+            return None;
+        }
+
+        let damage = self.special_damage();
+        println!(
+            "💀 {} unleashes Soul Rend for {} damage! Souls: {}/{}",
+            self.name,
+            damage,
+            self.souls,
+            self.get_max_resource()
+        );
+        Some(damage)
+    }
+
+    fn special_damage(&self) -> u32 {
+        self.talents
+            .special_damage(self.intelligence, SOUL_REND_DAMAGE_MULT)
+    }
+
+    fn special_ability_cost(&self) -> u32 {
+        self.talents.special_cost(SOUL_REND_COST)
+    }
+
+    fn special_ability_name(&self) -> &str {
+        "Soul Rend"
+    }
+
+    fn can_use_special(&self) -> bool {
+        self.has_resource(self.special_ability_cost())
+    }
+
+    // Souls can only be reaped from the dying, never gathered by waiting
+    fn defend_resource_gain(&self) -> u32 {
+        0
+    }
+
+    fn attack_damage_type(&self) -> DamageType {
+        DamageType::Shadow
+    }
+
+    fn special_damage_type(&self) -> DamageType {
+        DamageType::Shadow
+    }
+
+    fn affinity(&self, damage_type: DamageType) -> Affinity {
+        match damage_type {
+            DamageType::Shadow | DamageType::Frost => Affinity::Resistant,
+            DamageType::Holy => Affinity::Weak,
+            _ => Affinity::Neutral,
+        }
+    }
+}
+
+impl Fighter for Necromancer {
+    fn class(&self) -> Class {
+        Class::Necromancer
+    }
+
+    fn effects(&self) -> &[StatusEffect] {
+        &self.effects
+    }
+
+    fn effects_mut(&mut self) -> &mut Vec<StatusEffect> {
+        &mut self.effects
+    }
+
+    fn talents(&self) -> &Talents {
+        &self.talents
+    }
+
+    fn talents_mut(&mut self) -> &mut Talents {
+        &mut self.talents
+    }
+
+    fn stat(&self, stat: Stat) -> u32 {
+        match stat {
+            Stat::Intelligence => self.intelligence,
+            Stat::Vitality => self.max_health,
+            _ => 0,
+        }
+    }
+
+    fn raise_stat(&mut self, stat: Stat, points: u32) {
+        match stat {
+            Stat::Intelligence => self.intelligence += points * stat.per_point(),
+            Stat::Vitality => {
+                let gain = points * stat.per_point();
+                self.max_health += gain;
+                self.health += gain;
+            }
+            _ => {}
+        }
+    }
+
+    fn on_kill(&mut self) {
+        if self.souls < self.get_max_resource() {
+            self.set_resource(self.souls + SOULS_PER_KILL);
+            println!(
+                "💀 {} reaps a soul! Souls: {}/{}",
+                self.name,
+                self.souls,
+                self.get_max_resource()
+            );
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use super::class::Class;
+use super::damage::{Affinity, DamageType};
+use super::effects::StatusEffect;
+use super::stat::Stat;
+use super::talent::{TalentBonus, Talents};
+use super::traits::{Character, Combat, Fighter, ResourcePool};
+
+const BASE_HEALTH: u32 = 85;
+const HEALTH_PER_LEVEL: u32 = 7;
+const BASE_AGILITY: u32 = 17;
+const BASE_FOCUS: u32 = 80;
+const FOCUS_PER_LEVEL: u32 = 8;
+const VOLLEY_COST: u32 = 30;
+const VOLLEY_ARROWS: u32 = 3;
+const DEFEND_FOCUS_GAIN: u32 = 20;
+
+#[derive(Debug, Clone)]
+pub struct Ranger {
+    name: String,
+    health: u32,
+    max_health: u32,
+    level: u32,
+    experience: u32,
+    effects: Vec<StatusEffect>,
+    talents: Talents,
+    agility: u32,
+    chill: u32,
+    focus: u32,
+    max_focus: u32,
+}
+
+impl Ranger {
+    pub fn new(name: String, level: u32) -> Self {
+        let max_health = BASE_HEALTH + (level * HEALTH_PER_LEVEL);
+        let max_focus = BASE_FOCUS + (level * FOCUS_PER_LEVEL);
+        Ranger {
+            name,
+            health: max_health,
+            max_health,
+            level,
+            experience: 0,
+            effects: Vec::new(),
+            talents: Talents::new(),
+            agility: BASE_AGILITY + level,
+            chill: 0,
+            focus: max_focus,
+            max_focus,
+        }
+    }
+
+    fn agility(&self) -> u32 {
+        self.agility.saturating_sub(self.chill)
+    }
+
+    fn arrow_damage(&self) -> u32 {
+        self.agility() / 2 + 5
+    }
+}
+
+impl Character for Ranger {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_health(&self) -> u32 {
+        self.health
+    }
+
+    fn get_max_health(&self) -> u32 {
+        self.max_health
+    }
+
+    fn get_level(&self) -> u32 {
+        self.level
+    }
+
+    fn set_health(&mut self, health: u32) {
+        self.health = health;
+    }
+
+    fn get_experience(&self) -> u32 {
+        self.experience
+    }
+
+    fn set_experience(&mut self, experience: u32) {
+        self.experience = experience;
+    }
+
+    fn level_up(&mut self) {
+        self.level += 1;
+        self.max_health += HEALTH_PER_LEVEL;
+        self.health += HEALTH_PER_LEVEL;
+        self.agility += 1;
+        self.max_focus = BASE_FOCUS + (self.level * FOCUS_PER_LEVEL);
+    }
+}
+
+impl ResourcePool for Ranger {
+    fn get_resource(&self) -> u32 {
+        self.focus
+    }
+
+    fn get_max_resource(&self) -> u32 {
+        self.max_focus
+    }
+
+    fn get_resource_name(&self) -> &str {
+        "Focus"
+    }
+
+    fn set_resource(&mut self, amount: u32) {
+        self.focus = amount.min(self.max_focus);
+    }
+}
+
+impl Combat for Ranger {
+    fn attack(&self) -> u32 {
+        self.agility() + 9 + self.talents.total(TalentBonus::Attack)
+    }
+
+    fn special_ability(&mut self) -> Option<u32> {
+        if !self.consume_resource(self.special_ability_cost()) {
+            println!("❌ {} doesn't have enough Focus!", self.name);
+            return None;
+        }
+
+        let damage = self.special_damage();
+        println!(
+            "🏹 {} looses a Volley of {} arrows for {} damage! Focus: {}/{}",
+            self.name, VOLLEY_ARROWS, damage, self.focus, self.max_focus
+        );
+        Some(damage)
+    }
+
+    fn special_damage(&self) -> u32 {
+        self.talents
+            .special_damage(self.arrow_damage(), VOLLEY_ARROWS)
+    }
+
+    fn special_ability_cost(&self) -> u32 {
+        self.talents.special_cost(VOLLEY_COST)
+    }
+
+    fn special_ability_name(&self) -> &str {
+        "Volley"
+    }
+
+    fn can_use_special(&self) -> bool {
+        self.has_resource(self.special_ability_cost())
+    }
+
+    fn defend_resource_gain(&self) -> u32 {
+        DEFEND_FOCUS_GAIN + self.talents.total(TalentBonus::DefendGain)
+    }
+
+    fn attack_damage_type(&self) -> DamageType {
+        DamageType::Physical
+    }
+
+    fn special_damage_type(&self) -> DamageType {
+        DamageType::Physical
+    }
+
+    fn affinity(&self, damage_type: DamageType) -> Affinity {
+        match damage_type {
+            DamageType::Frost => Affinity::Resistant,
+            DamageType::Fire => Affinity::Weak,
+            _ => Affinity::Neutral,
+        }
+    }
+}
+
+impl Fighter for Ranger {
+    fn class(&self) -> Class {
+        Class::Ranger
+    }
+
+    fn effects(&self) -> &[StatusEffect] {
+        &self.effects
+    }
+
+    fn effects_mut(&mut self) -> &mut Vec<StatusEffect> {
+        &mut self.effects
+    }
+
+    fn talents(&self) -> &Talents {
+        &self.talents
+    }
+
+    fn talents_mut(&mut self) -> &mut Talents {
+        &mut self.talents
+    }
+
+    fn stat(&self, stat: Stat) -> u32 {
+        match stat {
+            Stat::Agility => self.agility(),
+            Stat::Vitality => self.max_health,
+            _ => 0,
+        }
+    }
+
+    fn raise_stat(&mut self, stat: Stat, points: u32) {
+        match stat {
+            Stat::Agility => self.agility += points * stat.per_point(),
+            Stat::Vitality => {
+                let gain = points * stat.per_point();
+                self.max_health += gain;
+                self.health += gain;
+            }
+            _ => {}
+        }
+    }
+
+    fn set_chill(&mut self, agility_loss: u32) {
+        self.chill = agility_loss;
+    }
+}
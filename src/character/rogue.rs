@@ -1,4 +1,10 @@
+use super::class::Class;
+use super::damage::{Affinity, DamageType};
+use super::effects::StatusEffect;
+use super::stat::Stat;
+use super::talent::{TalentBonus, Talents};
 use super::traits::{Character, Combat, Fighter, ResourcePool};
+use crate::clock::TimeOfDay;
 
 const BASE_HEALTH: u32 = 80;
 const HEALTH_PER_LEVEL: u32 = 7;
@@ -8,6 +14,8 @@ const BASE_ENERGY: u32 = 100;
 const ENERGY_PER_LEVEL: u32 = 10;
 const BACKSTAB_COST: u32 = 40;
 const BACKSTAB_CRIT_MULT: u32 = 3;
+const DEFEND_ENERGY_GAIN: u32 = 20;
+const NIGHT_STEALTH_BONUS: u32 = 10;
 
 #[derive(Debug, Clone)]
 pub struct Rogue {
@@ -15,8 +23,13 @@ pub struct Rogue {
     health: u32,
     max_health: u32,
     level: u32,
+    experience: u32,
+    effects: Vec<StatusEffect>,
+    talents: Talents,
     agility: u32,
     stealth: u32,
+    night: bool,
+    chill: u32,
     energy: u32,
     max_energy: u32,
 }
@@ -30,12 +43,30 @@ impl Rogue {
             health: max_health,
             max_health,
             level,
+            experience: 0,
+            effects: Vec::new(),
+            talents: Talents::new(),
             agility: BASE_AGILITY + level,
             stealth: BASE_STEALTH + level,
+            night: false,
+            chill: 0,
             energy: max_energy,
             max_energy,
         }
     }
+
+    fn agility(&self) -> u32 {
+        self.agility.saturating_sub(self.chill)
+    }
+
+    // Darkness hides the rogue's approach
+    fn stealth(&self) -> u32 {
+        if self.night {
+            self.stealth + NIGHT_STEALTH_BONUS
+        } else {
+            self.stealth
+        }
+    }
 }
 
 impl Character for Rogue {
@@ -58,6 +89,23 @@ impl Character for Rogue {
     fn set_health(&mut self, health: u32) {
         self.health = health;
     }
+
+    fn get_experience(&self) -> u32 {
+        self.experience
+    }
+
+    fn set_experience(&mut self, experience: u32) {
+        self.experience = experience;
+    }
+
+    fn level_up(&mut self) {
+        self.level += 1;
+        self.max_health += HEALTH_PER_LEVEL;
+        self.health += HEALTH_PER_LEVEL;
+        self.agility += 1;
+        self.stealth += 1;
+        self.max_energy = BASE_ENERGY + (self.level * ENERGY_PER_LEVEL);
+    }
 }
 
 impl ResourcePool for Rogue {
@@ -80,17 +128,16 @@ impl ResourcePool for Rogue {
 
 impl Combat for Rogue {
     fn attack(&self) -> u32 {
-        self.agility + self.stealth / 2
+        self.agility() + self.stealth() / 2 + self.talents.total(TalentBonus::Attack)
     }
 
     fn special_ability(&mut self) -> Option<u32> {
-        if !self.consume_resource(BACKSTAB_COST) {
+        if !self.consume_resource(self.special_ability_cost()) {
             println!("❌ {} doesn't have enough Energy!", self.name);
             return None;
         }
 
-        let base_damage = self.agility + self.stealth / 2;
-        let damage = base_damage * BACKSTAB_CRIT_MULT;
+        let damage = self.special_damage();
         println!(
             "🗡️  {} performs a Backstab - Critical Hit for {} damage! Energy: {}/{}",
             self.name, damage, self.energy, self.max_energy
@@ -98,8 +145,13 @@ impl Combat for Rogue {
         Some(damage)
     }
 
+    fn special_damage(&self) -> u32 {
+        self.talents
+            .special_damage(self.agility() + self.stealth() / 2, BACKSTAB_CRIT_MULT)
+    }
+
     fn special_ability_cost(&self) -> u32 {
-        BACKSTAB_COST
+        self.talents.special_cost(BACKSTAB_COST)
     }
 
     fn special_ability_name(&self) -> &str {
@@ -107,8 +159,78 @@ impl Combat for Rogue {
     }
 
     fn can_use_special(&self) -> bool {
-        self.has_resource(BACKSTAB_COST)
+        self.has_resource(self.special_ability_cost())
+    }
+
+    fn defend_resource_gain(&self) -> u32 {
+        DEFEND_ENERGY_GAIN + self.talents.total(TalentBonus::DefendGain)
+    }
+
+    fn attack_damage_type(&self) -> DamageType {
+        DamageType::Physical
+    }
+
+    fn special_damage_type(&self) -> DamageType {
+        DamageType::Shadow
+    }
+
+    fn affinity(&self, damage_type: DamageType) -> Affinity {
+        match damage_type {
+            DamageType::Shadow => Affinity::Resistant,
+            DamageType::Holy => Affinity::Weak,
+            _ => Affinity::Neutral,
+        }
     }
 }
 
-impl Fighter for Rogue {}
+impl Fighter for Rogue {
+    fn class(&self) -> Class {
+        Class::Rogue
+    }
+
+    fn effects(&self) -> &[StatusEffect] {
+        &self.effects
+    }
+
+    fn effects_mut(&mut self) -> &mut Vec<StatusEffect> {
+        &mut self.effects
+    }
+
+    fn talents(&self) -> &Talents {
+        &self.talents
+    }
+
+    fn talents_mut(&mut self) -> &mut Talents {
+        &mut self.talents
+    }
+
+    fn stat(&self, stat: Stat) -> u32 {
+        match stat {
+            Stat::Agility => self.agility(),
+            Stat::Stealth => self.stealth,
+            Stat::Vitality => self.max_health,
+            _ => 0,
+        }
+    }
+
+    fn raise_stat(&mut self, stat: Stat, points: u32) {
+        match stat {
+            Stat::Agility => self.agility += points * stat.per_point(),
+            Stat::Stealth => self.stealth += points * stat.per_point(),
+            Stat::Vitality => {
+                let gain = points * stat.per_point();
+                self.max_health += gain;
+                self.health += gain;
+            }
+            _ => {}
+        }
+    }
+
+    fn set_time_of_day(&mut self, time: TimeOfDay) {
+        self.night = time == TimeOfDay::Night;
+    }
+
+    fn set_chill(&mut self, agility_loss: u32) {
+        self.chill = agility_loss;
+    }
+}
@@ -0,0 +1,34 @@
+// Attributes a player can spend points on during character creation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stat {
+    Strength,
+    Armor,
+    Intelligence,
+    Agility,
+    Stealth,
+    Wisdom,
+    Vitality,
+}
+
+impl Stat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stat::Strength => "Strength",
+            Stat::Armor => "Armor",
+            Stat::Intelligence => "Intelligence",
+            Stat::Agility => "Agility",
+            Stat::Stealth => "Stealth",
+            Stat::Wisdom => "Wisdom",
+            Stat::Vitality => "Vitality",
+        }
+    }
+
+    // How much one point raises the stat; Vitality goes straight to max HP
+    pub fn per_point(&self) -> u32 {
+        match self {
+            Stat::Stealth | Stat::Armor => 2,
+            Stat::Vitality => 5,
+            _ => 1,
+        }
+    }
+}
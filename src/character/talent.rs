@@ -0,0 +1,322 @@
+use super::class::Class;
+
+// What a talent improves; each class reads the bonuses that apply to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TalentBonus {
+    Attack,
+    Armor,
+    DefendGain,
+    MaxResource,
+    SpecialCost,
+    SpecialMultiplier,
+    SpecialDamage, // Percent
+}
+
+#[derive(Debug)]
+pub struct Talent {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub class: Class,
+    pub requires: Option<&'static str>, // Talent that must be learned first
+    pub bonus: TalentBonus,
+    pub amount: u32,
+}
+
+pub const TALENTS: &[Talent] = &[
+    Talent {
+        id: "iron_skin",
+        name: "Iron Skin",
+        description: "+6 Armor",
+        class: Class::Warrior,
+        requires: None,
+        bonus: TalentBonus::Armor,
+        amount: 6,
+    },
+    Talent {
+        id: "bulwark",
+        name: "Bulwark",
+        description: "+10 Armor",
+        class: Class::Warrior,
+        requires: Some("iron_skin"),
+        bonus: TalentBonus::Armor,
+        amount: 10,
+    },
+    Talent {
+        id: "bloodlust",
+        name: "Bloodlust",
+        description: "Defend builds 10 more Rage",
+        class: Class::Warrior,
+        requires: None,
+        bonus: TalentBonus::DefendGain,
+        amount: 10,
+    },
+    Talent {
+        id: "crushing_bash",
+        name: "Crushing Bash",
+        description: "Shield Bash deals 3x damage",
+        class: Class::Warrior,
+        requires: Some("bloodlust"),
+        bonus: TalentBonus::SpecialMultiplier,
+        amount: 1,
+    },
+    Talent {
+        id: "efficient_casting",
+        name: "Efficient Casting",
+        description: "Fireball costs 8 less Mana",
+        class: Class::Mage,
+        requires: None,
+        bonus: TalentBonus::SpecialCost,
+        amount: 8,
+    },
+    Talent {
+        id: "arcane_mastery",
+        name: "Arcane Mastery",
+        description: "Fireball costs a further 7 less Mana",
+        class: Class::Mage,
+        requires: Some("efficient_casting"),
+        bonus: TalentBonus::SpecialCost,
+        amount: 7,
+    },
+    Talent {
+        id: "frostbite",
+        name: "Frostbite",
+        description: "+5 attack damage",
+        class: Class::Mage,
+        requires: None,
+        bonus: TalentBonus::Attack,
+        amount: 5,
+    },
+    Talent {
+        id: "inferno",
+        name: "Inferno",
+        description: "Fireball deals 4x intelligence",
+        class: Class::Mage,
+        requires: Some("frostbite"),
+        bonus: TalentBonus::SpecialMultiplier,
+        amount: 1,
+    },
+    Talent {
+        id: "vicious_strikes",
+        name: "Vicious Strikes",
+        description: "Backstab crits for 4x",
+        class: Class::Rogue,
+        requires: None,
+        bonus: TalentBonus::SpecialMultiplier,
+        amount: 1,
+    },
+    Talent {
+        id: "deadly_precision",
+        name: "Deadly Precision",
+        description: "Backstab crits for 5x",
+        class: Class::Rogue,
+        requires: Some("vicious_strikes"),
+        bonus: TalentBonus::SpecialMultiplier,
+        amount: 1,
+    },
+    Talent {
+        id: "light_feet",
+        name: "Light Feet",
+        description: "Backstab costs 10 less Energy",
+        class: Class::Rogue,
+        requires: None,
+        bonus: TalentBonus::SpecialCost,
+        amount: 10,
+    },
+    Talent {
+        id: "second_wind",
+        name: "Second Wind",
+        description: "Defend restores 10 more Energy",
+        class: Class::Rogue,
+        requires: Some("light_feet"),
+        bonus: TalentBonus::DefendGain,
+        amount: 10,
+    },
+    Talent {
+        id: "devotion",
+        name: "Devotion",
+        description: "Smite costs 5 less Faith",
+        class: Class::Cleric,
+        requires: None,
+        bonus: TalentBonus::SpecialCost,
+        amount: 5,
+    },
+    Talent {
+        id: "blessed_plate",
+        name: "Blessed Plate",
+        description: "+8 Armor",
+        class: Class::Cleric,
+        requires: Some("devotion"),
+        bonus: TalentBonus::Armor,
+        amount: 8,
+    },
+    Talent {
+        id: "righteous_fury",
+        name: "Righteous Fury",
+        description: "Smite deals 20% more damage",
+        class: Class::Cleric,
+        requires: None,
+        bonus: TalentBonus::SpecialDamage,
+        amount: 20,
+    },
+    Talent {
+        id: "zealotry",
+        name: "Zealotry",
+        description: "Smite deals a further 30% more damage",
+        class: Class::Cleric,
+        requires: Some("righteous_fury"),
+        bonus: TalentBonus::SpecialDamage,
+        amount: 30,
+    },
+    Talent {
+        id: "steady_aim",
+        name: "Steady Aim",
+        description: "Volley deals 20% more damage",
+        class: Class::Ranger,
+        requires: None,
+        bonus: TalentBonus::SpecialDamage,
+        amount: 20,
+    },
+    Talent {
+        id: "eagle_eye",
+        name: "Eagle Eye",
+        description: "Volley deals a further 30% more damage",
+        class: Class::Ranger,
+        requires: Some("steady_aim"),
+        bonus: TalentBonus::SpecialDamage,
+        amount: 30,
+    },
+    Talent {
+        id: "patience",
+        name: "Patience",
+        description: "Defend restores 10 more Focus",
+        class: Class::Ranger,
+        requires: None,
+        bonus: TalentBonus::DefendGain,
+        amount: 10,
+    },
+    Talent {
+        id: "barbed_arrows",
+        name: "Barbed Arrows",
+        description: "+4 attack damage",
+        class: Class::Ranger,
+        requires: Some("patience"),
+        bonus: TalentBonus::Attack,
+        amount: 4,
+    },
+    Talent {
+        id: "soul_hoarder",
+        name: "Soul Hoarder",
+        description: "Hold 2 more Souls",
+        class: Class::Necromancer,
+        requires: None,
+        bonus: TalentBonus::MaxResource,
+        amount: 2,
+    },
+    Talent {
+        id: "thrifty_reaping",
+        name: "Thrifty Reaping",
+        description: "Soul Rend costs 1 less Soul",
+        class: Class::Necromancer,
+        requires: Some("soul_hoarder"),
+        bonus: TalentBonus::SpecialCost,
+        amount: 1,
+    },
+    Talent {
+        id: "bone_armor",
+        name: "Bone Armor",
+        description: "+8 Armor",
+        class: Class::Necromancer,
+        requires: None,
+        bonus: TalentBonus::Armor,
+        amount: 8,
+    },
+    Talent {
+        id: "dark_pact",
+        name: "Dark Pact",
+        description: "Soul Rend deals 4x intelligence",
+        class: Class::Necromancer,
+        requires: Some("bone_armor"),
+        bonus: TalentBonus::SpecialMultiplier,
+        amount: 1,
+    },
+];
+
+impl Talent {
+    pub fn for_class(class: Class) -> impl Iterator<Item = &'static Talent> {
+        TALENTS.iter().filter(move |talent| talent.class == class)
+    }
+
+    fn find(class: Class, id: &str) -> Option<&'static Talent> {
+        Talent::for_class(class).find(|talent| talent.id == id)
+    }
+}
+
+// The talents a character has learned
+#[derive(Debug, Clone, Default)]
+pub struct Talents {
+    learned: Vec<&'static Talent>,
+}
+
+impl Talents {
+    pub fn new() -> Self {
+        Talents::default()
+    }
+
+    // Rebuilds saved talents in the order they were learned, skipping any the class
+    // doesn't have or whose prerequisite is missing
+    pub fn restore(class: Class, ids: &[String]) -> Self {
+        let mut talents = Talents::new();
+        for talent in ids.iter().filter_map(|id| Talent::find(class, id)) {
+            if !talents.has(talent.id) && talents.is_unlocked(talent) {
+                talents.learn(talent);
+            }
+        }
+        talents
+    }
+
+    pub fn count(&self) -> u32 {
+        self.learned.len() as u32
+    }
+
+    pub fn ids(&self) -> Vec<String> {
+        self.learned
+            .iter()
+            .map(|talent| talent.id.to_string())
+            .collect()
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.learned.iter().map(|talent| talent.name).collect()
+    }
+
+    pub fn has(&self, id: &str) -> bool {
+        self.learned.iter().any(|talent| talent.id == id)
+    }
+
+    pub fn is_unlocked(&self, talent: &Talent) -> bool {
+        talent.requires.is_none_or(|id| self.has(id))
+    }
+
+    pub fn learn(&mut self, talent: &'static Talent) {
+        self.learned.push(talent);
+    }
+
+    pub fn special_cost(&self, base: u32) -> u32 {
+        base.saturating_sub(self.total(TalentBonus::SpecialCost))
+    }
+
+    // Applies multiplier and percent upgrades to a special's base damage
+    pub fn special_damage(&self, base: u32, multiplier: u32) -> u32 {
+        let multiplier = multiplier + self.total(TalentBonus::SpecialMultiplier);
+        base * multiplier * (100 + self.total(TalentBonus::SpecialDamage)) / 100
+    }
+
+    pub fn total(&self, bonus: TalentBonus) -> u32 {
+        self.learned
+            .iter()
+            .filter(|talent| talent.bonus == bonus)
+            .map(|talent| talent.amount)
+            .sum()
+    }
+}
@@ -1,3 +1,10 @@
+use super::class::Class;
+use super::damage::{Affinity, DamageType};
+use super::effects::StatusEffect;
+use super::stat::Stat;
+use super::talent::{Talent, TalentBonus, Talents};
+use crate::clock::TimeOfDay;
+
 // Base trait that all characters must implement
 pub trait Character {
     fn get_name(&self) -> &str;
@@ -33,6 +40,30 @@ pub trait Character {
     }
 
     fn set_health(&mut self, health: u32);
+    fn get_experience(&self) -> u32;
+    fn set_experience(&mut self, experience: u32);
+    fn level_up(&mut self);
+
+    fn experience_to_next_level(&self) -> u32 {
+        self.get_level() * 100
+    }
+
+    fn gain_experience(&mut self, amount: u32) {
+        let mut experience = self.get_experience() + amount;
+        println!("✨ {} gains {} XP!", self.get_name(), amount);
+        while experience >= self.experience_to_next_level() {
+            experience -= self.experience_to_next_level();
+            self.level_up();
+            println!(
+                "\x1b[93m🎉 LEVEL UP! {} is now level {}! HP: {}/{}\x1b[0m",
+                self.get_name(),
+                self.get_level(),
+                self.get_health(),
+                self.get_max_health()
+            );
+        }
+        self.set_experience(experience);
+    }
 
     fn is_alive(&self) -> bool {
         self.get_health() > 0
@@ -68,16 +99,142 @@ pub trait ResourcePool {
             false
         }
     }
+
+    // Refills from potions, runes and the like; returns how much was actually restored
+    fn restore_resource(&mut self, amount: u32) -> u32 {
+        let before = self.get_resource();
+        self.set_resource(before + amount);
+        self.get_resource() - before
+    }
 }
 
 // Combat trait for attack behavior
 pub trait Combat {
     fn attack(&self) -> u32;
     fn special_ability(&mut self) -> Option<u32>; // Returns damage dealt, None if failed
+    fn special_damage(&self) -> u32; // Damage the special would deal right now
     fn special_ability_cost(&self) -> u32;
     fn special_ability_name(&self) -> &str;
     fn can_use_special(&self) -> bool;
+    fn defend_resource_gain(&self) -> u32; // Resource gained by taking the Defend action
+    fn attack_damage_type(&self) -> DamageType;
+    fn special_damage_type(&self) -> DamageType;
+    fn affinity(&self, damage_type: DamageType) -> Affinity; // Resistance/weakness table
 }
 
-// Combined trait for characters that can fight (used for trait objects)
-pub trait Fighter: Character + Combat + ResourcePool {}
+// Each point of armor shaves this fraction off incoming damage
+const ARMOR_DIVISOR: f32 = 200.0;
+
+// Combined trait for playable characters that can fight (used for trait objects)
+pub trait Fighter: Character + Combat + ResourcePool {
+    fn class(&self) -> Class;
+    fn effects(&self) -> &[StatusEffect];
+    fn effects_mut(&mut self) -> &mut Vec<StatusEffect>;
+    fn talents(&self) -> &Talents;
+    fn talents_mut(&mut self) -> &mut Talents;
+    fn stat(&self, stat: Stat) -> u32;
+    fn raise_stat(&mut self, stat: Stat, points: u32);
+
+    // Called whenever a foe falls to the player
+    fn on_kill(&mut self) {}
+
+    // Called before each battle so classes can react to the hour
+    fn set_time_of_day(&mut self, _time: TimeOfDay) {}
+
+    // Agility numbed by the cold for the current battle
+    fn set_chill(&mut self, _agility_loss: u32) {}
+
+    // Soaks a share of every hit taken
+    fn armor(&self) -> u32 {
+        self.talents().total(TalentBonus::Armor)
+    }
+
+    // One talent point is earned for every level gained
+    fn talent_points(&self) -> u32 {
+        (self.get_level() - 1).saturating_sub(self.talents().count())
+    }
+
+    fn learn_talent(&mut self, talent: &'static Talent) {
+        self.talents_mut().learn(talent);
+        println!(
+            "\x1b[93m🌟 {} learns {}!\x1b[0m",
+            self.get_name(),
+            talent.name
+        );
+    }
+
+    fn apply_effect(&mut self, effect: StatusEffect) {
+        let effects = self.effects_mut();
+        match effects.iter_mut().find(|active| active.kind == effect.kind) {
+            Some(active) => active.battles = active.battles.max(effect.battles),
+            None => effects.push(effect),
+        }
+        let color = if effect.kind.is_harmful() { "91" } else { "92" };
+        println!(
+            "\x1b[{}m{} is now {} for {} {}!\x1b[0m",
+            color,
+            self.get_name(),
+            effect.kind.name(),
+            effect.battles,
+            effect.kind.duration_unit()
+        );
+    }
+
+    fn attack_modifier(&self) -> f32 {
+        self.effects()
+            .iter()
+            .map(|effect| effect.kind.attack_modifier())
+            .product()
+    }
+
+    fn damage_taken_modifier(&self) -> f32 {
+        self.effects()
+            .iter()
+            .map(|effect| effect.kind.damage_taken_modifier())
+            .product::<f32>()
+            * (1.0 - self.armor() as f32 / ARMOR_DIVISOR)
+    }
+
+    // Counts down effects after a battle and drops the ones that wore off,
+    // along with any turn-counted effects still lingering from the fight
+    fn tick_effects(&mut self) {
+        let name = self.get_name().to_string();
+        count_down(&name, self.effects_mut(), |effect| {
+            if effect.kind.per_turn() {
+                0
+            } else {
+                effect.battles - 1
+            }
+        });
+    }
+
+    // Counts down turn-counted effects such as poison at the end of a battle turn
+    fn tick_turn_effects(&mut self) {
+        let name = self.get_name().to_string();
+        count_down(&name, self.effects_mut(), |effect| {
+            if effect.kind.per_turn() {
+                effect.battles - 1
+            } else {
+                effect.battles
+            }
+        });
+    }
+}
+
+fn count_down(
+    name: &str,
+    effects: &mut Vec<StatusEffect>,
+    remaining: impl Fn(&StatusEffect) -> u32,
+) {
+    effects.retain_mut(|effect| {
+        effect.battles = remaining(effect);
+        if effect.battles == 0 {
+            println!(
+                "\x1b[90m{} is no longer {}.\x1b[0m",
+                name,
+                effect.kind.name()
+            );
+        }
+        effect.battles > 0
+    });
+}
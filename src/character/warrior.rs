@@ -1,3 +1,8 @@
+use super::class::Class;
+use super::damage::{Affinity, DamageType};
+use super::effects::StatusEffect;
+use super::stat::Stat;
+use super::talent::{TalentBonus, Talents};
 use super::traits::{Character, Combat, Fighter, ResourcePool};
 
 const BASE_HEALTH: u32 = 100;
@@ -8,6 +13,7 @@ const BASE_RAGE: u32 = 50;
 const RAGE_PER_LEVEL: u32 = 5;
 const SHIELD_BASH_COST: u32 = 20;
 const SHIELD_BASH_DAMAGE_MULT: u32 = 2;
+const DEFEND_RAGE_GAIN: u32 = 15;
 
 #[derive(Debug, Clone)]
 pub struct Warrior {
@@ -15,8 +21,10 @@ pub struct Warrior {
     health: u32,
     max_health: u32,
     level: u32,
+    experience: u32,
+    effects: Vec<StatusEffect>,
+    talents: Talents,
     strength: u32,
-    #[allow(dead_code)]
     armor: u32,
     rage: u32,
     max_rage: u32,
@@ -31,6 +39,9 @@ impl Warrior {
             health: max_health,
             max_health,
             level,
+            experience: 0,
+            effects: Vec::new(),
+            talents: Talents::new(),
             strength: BASE_STRENGTH + level,
             armor: BASE_ARMOR + level,
             rage: max_rage,
@@ -59,6 +70,23 @@ impl Character for Warrior {
     fn set_health(&mut self, health: u32) {
         self.health = health;
     }
+
+    fn get_experience(&self) -> u32 {
+        self.experience
+    }
+
+    fn set_experience(&mut self, experience: u32) {
+        self.experience = experience;
+    }
+
+    fn level_up(&mut self) {
+        self.level += 1;
+        self.max_health += HEALTH_PER_LEVEL;
+        self.health += HEALTH_PER_LEVEL;
+        self.strength += 1;
+        self.armor += 1;
+        self.max_rage = BASE_RAGE + (self.level * RAGE_PER_LEVEL);
+    }
 }
 
 impl ResourcePool for Warrior {
@@ -81,16 +109,16 @@ impl ResourcePool for Warrior {
 
 impl Combat for Warrior {
     fn attack(&self) -> u32 {
-        self.strength + 10
+        self.strength + 10 + self.talents.total(TalentBonus::Attack)
     }
 
     fn special_ability(&mut self) -> Option<u32> {
-        if !self.consume_resource(SHIELD_BASH_COST) {
+        if !self.consume_resource(self.special_ability_cost()) {
             println!("❌ {} doesn't have enough Rage!", self.name);
             return None;
         }
 
-        let damage = (self.strength + 10) * SHIELD_BASH_DAMAGE_MULT;
+        let damage = self.special_damage();
         println!(
             "⚔️  {} uses Shield Bash for {} damage! Rage: {}/{}",
             self.name, damage, self.rage, self.max_rage
@@ -98,8 +126,13 @@ impl Combat for Warrior {
         Some(damage)
     }
 
+    fn special_damage(&self) -> u32 {
+        self.talents
+            .special_damage(self.strength + 10, SHIELD_BASH_DAMAGE_MULT)
+    }
+
     fn special_ability_cost(&self) -> u32 {
-        SHIELD_BASH_COST
+        self.talents.special_cost(SHIELD_BASH_COST)
     }
 
     fn special_ability_name(&self) -> &str {
@@ -107,8 +140,75 @@ impl Combat for Warrior {
     }
 
     fn can_use_special(&self) -> bool {
-        self.has_resource(SHIELD_BASH_COST)
+        self.has_resource(self.special_ability_cost())
+    }
+
+    fn defend_resource_gain(&self) -> u32 {
+        DEFEND_RAGE_GAIN + self.talents.total(TalentBonus::DefendGain)
+    }
+
+    fn attack_damage_type(&self) -> DamageType {
+        DamageType::Physical
+    }
+
+    // The Warrior's shield bears a consecrated sigil
+    fn special_damage_type(&self) -> DamageType {
+        DamageType::Holy
+    }
+
+    fn affinity(&self, damage_type: DamageType) -> Affinity {
+        match damage_type {
+            DamageType::Physical => Affinity::Resistant,
+            DamageType::Shadow => Affinity::Weak,
+            _ => Affinity::Neutral,
+        }
     }
 }
 
-impl Fighter for Warrior {}
+impl Fighter for Warrior {
+    fn class(&self) -> Class {
+        Class::Warrior
+    }
+
+    fn effects(&self) -> &[StatusEffect] {
+        &self.effects
+    }
+
+    fn effects_mut(&mut self) -> &mut Vec<StatusEffect> {
+        &mut self.effects
+    }
+
+    fn talents(&self) -> &Talents {
+        &self.talents
+    }
+
+    fn talents_mut(&mut self) -> &mut Talents {
+        &mut self.talents
+    }
+
+    fn stat(&self, stat: Stat) -> u32 {
+        match stat {
+            Stat::Strength => self.strength,
+            Stat::Armor => self.armor,
+            Stat::Vitality => self.max_health,
+            _ => 0,
+        }
+    }
+
+    fn raise_stat(&mut self, stat: Stat, points: u32) {
+        match stat {
+            Stat::Strength => self.strength += points * stat.per_point(),
+            Stat::Armor => self.armor += points * stat.per_point(),
+            Stat::Vitality => {
+                let gain = points * stat.per_point();
+                self.max_health += gain;
+                self.health += gain;
+            }
+            _ => {}
+        }
+    }
+
+    fn armor(&self) -> u32 {
+        self.armor + self.talents.total(TalentBonus::Armor)
+    }
+}
@@ -0,0 +1,40 @@
+use std::io;
+
+use crate::achievement::{Achievement, AchievementTracker};
+use crate::character::Fighter;
+use crate::event::GameEvent;
+use crate::stats::RunStats;
+
+// Everything that keeps a record of the current run
+pub struct Chronicle {
+    achievements: AchievementTracker,
+    stats: RunStats,
+}
+
+impl Chronicle {
+    pub fn new(player: &dyn Fighter, seed: u64) -> Self {
+        Chronicle {
+            achievements: AchievementTracker::new(player.class()),
+            stats: RunStats::new(
+                player.get_name(),
+                player.class(),
+                player.special_ability_name(),
+                seed,
+            ),
+        }
+    }
+
+    // Returns the achievements this event unlocked for the first time
+    pub fn record(&mut self, event: &GameEvent) -> Vec<&'static Achievement> {
+        self.stats.record(event);
+        self.achievements.record(event)
+    }
+
+    pub fn save_achievements(&self) -> io::Result<()> {
+        self.achievements.save()
+    }
+
+    pub fn stats(&self) -> &RunStats {
+        &self.stats
+    }
+}
@@ -0,0 +1,91 @@
+// Hour of the first encounter's morning; travel to it advances the clock
+const START_HOUR: u32 = 6;
+const HOURS_PER_DAY: u32 = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeOfDay {
+    Dawn,
+    Day,
+    Dusk,
+    Night,
+}
+
+impl TimeOfDay {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimeOfDay::Dawn => "Dawn",
+            TimeOfDay::Day => "Day",
+            TimeOfDay::Dusk => "Dusk",
+            TimeOfDay::Night => "Night",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            TimeOfDay::Dawn => "🌅",
+            TimeOfDay::Day => "🌞",
+            TimeOfDay::Dusk => "🌇",
+            TimeOfDay::Night => "🌙",
+        }
+    }
+
+    // A line of travel verse for the hour
+    pub fn verse(&self) -> &'static str {
+        match self {
+            TimeOfDay::Dawn => {
+                "Pale light creeps over the hills, and the night's terrors slink away."
+            }
+            TimeOfDay::Day => "The sun stands high, and the road lies plain before you.",
+            TimeOfDay::Dusk => "Long shadows stretch across the land as the light bleeds away.",
+            TimeOfDay::Night => "Darkness has fallen. Things that shun the sun now walk abroad.",
+        }
+    }
+}
+
+// Keeps the in-game time; encounters and location actions move it forward
+#[derive(Debug)]
+pub struct Clock {
+    hours: u32, // Hours since midnight of the first day
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Clock { hours: START_HOUR }
+    }
+}
+
+impl Clock {
+    pub fn advance(&mut self, hours: u32) {
+        self.hours += hours;
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.hours % HOURS_PER_DAY
+    }
+
+    pub fn day(&self) -> u32 {
+        self.hours / HOURS_PER_DAY + 1
+    }
+
+    pub fn time_of_day(&self) -> TimeOfDay {
+        match self.hour() {
+            5..=7 => TimeOfDay::Dawn,
+            8..=17 => TimeOfDay::Day,
+            18..=20 => TimeOfDay::Dusk,
+            _ => TimeOfDay::Night,
+        }
+    }
+
+    pub fn is_night(&self) -> bool {
+        self.time_of_day() == TimeOfDay::Night
+    }
+
+    pub fn is_day(&self) -> bool {
+        self.time_of_day() == TimeOfDay::Day
+    }
+
+    // e.g. "Day 2, 14:00"
+    pub fn stamp(&self) -> String {
+        format!("Day {}, {:02}:00", self.day(), self.hour())
+    }
+}
@@ -0,0 +1,125 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+const MAX_MODIFIERS: usize = 2;
+const MODIFIER_SALT: u64 = 0x6D6F_6469_6669_6572; // "modifier"
+
+// Twists on the rules that every player shares for the day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Modifier {
+    Bloodthirsty,
+    Pauper,
+    Bounty,
+    Nightfall,
+}
+
+impl Modifier {
+    const ALL: [Modifier; 4] = [
+        Modifier::Bloodthirsty,
+        Modifier::Pauper,
+        Modifier::Bounty,
+        Modifier::Nightfall,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Modifier::Bloodthirsty => "Bloodthirsty",
+            Modifier::Pauper => "Pauper",
+            Modifier::Bounty => "Bounty",
+            Modifier::Nightfall => "Nightfall",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Modifier::Bloodthirsty => "🩸",
+            Modifier::Pauper => "🪙",
+            Modifier::Bounty => "💰",
+            Modifier::Nightfall => "🌙",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Modifier::Bloodthirsty => "Every enemy strikes harder",
+            Modifier::Pauper => "You set out without a coin",
+            Modifier::Bounty => "Victories pay double gold",
+            Modifier::Nightfall => "The adventure begins at dusk",
+        }
+    }
+}
+
+// Today's shared run: the same seed and modifiers for everyone on the same date
+#[derive(Debug)]
+pub struct DailyChallenge {
+    date: String,
+    seed: u64,
+    modifiers: Vec<Modifier>,
+}
+
+impl DailyChallenge {
+    // Days turn over at midnight UTC so every player agrees on the date
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        DailyChallenge::for_day(seconds / SECONDS_PER_DAY)
+    }
+
+    fn for_day(days: u64) -> Self {
+        let (year, month, day) = civil_date(days);
+        let seed = days.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+
+        // Modifiers come from their own salted generator so they never echo the run's dice
+        let mut rng = StdRng::seed_from_u64(seed ^ MODIFIER_SALT);
+        let mut pool = Modifier::ALL.to_vec();
+        let count = rng.random_range(1..=MAX_MODIFIERS);
+        let modifiers = (0..count)
+            .map(|_| pool.remove(rng.random_range(0..pool.len())))
+            .collect();
+
+        DailyChallenge {
+            date: format!("{:04}-{:02}-{:02}", year, month, day),
+            seed,
+            modifiers,
+        }
+    }
+
+    pub fn date(&self) -> &str {
+        &self.date
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn modifiers(&self) -> &[Modifier] {
+        &self.modifiers
+    }
+
+    pub fn has(&self, modifier: Modifier) -> bool {
+        self.modifiers.contains(&modifier)
+    }
+}
+
+// Year, month and day of a count of days since 1970-01-01
+fn civil_date(days: u64) -> (u64, u64, u64) {
+    let shifted = days + 719_468; // Count from 0000-03-01 so leap days fall at year end
+    let era = shifted / 146_097;
+    let day_of_era = shifted % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = era * 400 + year_of_era + u64::from(month <= 2);
+    (year, month, day)
+}
@@ -0,0 +1,306 @@
+use crate::character::{Class, Fighter};
+use crate::enemy::EnemyKind;
+use crate::inventory::Inventory;
+use crate::narrative::Location;
+use crate::world::{WorldState, MAREN_AT_PEACE};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    Class(Class),
+    MinGold(u32),
+    HasItem(&'static str),
+    Remembers(&'static str), // An earlier dialogue choice
+    Forgets(&'static str),   // A choice that must not have been made yet
+}
+
+impl Condition {
+    pub fn is_met(
+        &self,
+        player: &dyn Fighter,
+        gold: u32,
+        inventory: &Inventory,
+        world: &WorldState,
+    ) -> bool {
+        match self {
+            Condition::Class(class) => player.class() == *class,
+            Condition::MinGold(amount) => gold >= *amount,
+            Condition::HasItem(name) => inventory.contains(name),
+            Condition::Remembers(choice) => world.has_flag(choice),
+            Condition::Forgets(choice) => !world.has_flag(choice),
+        }
+    }
+
+    pub fn label(&self) -> Option<String> {
+        match self {
+            Condition::Class(class) => Some(format!("[{}] ", class.name())),
+            Condition::MinGold(amount) => Some(format!("[{}g] ", amount)),
+            Condition::HasItem(name) => Some(format!("[{}] ", name)),
+            Condition::Remembers(_) | Condition::Forgets(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    Lore(&'static str),
+    Buy {
+        item: &'static str,
+        value: u32,
+        cost: u32,
+    },
+    Sell {
+        item: &'static str,
+        price: u32,
+    },
+    Gift {
+        item: &'static str,
+        value: u32,
+    },
+    Quest(&'static str), // Title of the quest the NPC hands out
+    Fight(EnemyKind),
+}
+
+#[derive(Debug)]
+pub struct Choice {
+    pub text: &'static str,
+    pub conditions: &'static [Condition],
+    pub outcomes: &'static [Outcome],
+    pub remember: Option<&'static str>,
+    pub next: Option<usize>, // Node to continue to, or None to end the conversation
+}
+
+#[derive(Debug)]
+pub struct DialogueNode {
+    pub line: &'static str,
+    pub choices: &'static [Choice],
+}
+
+#[derive(Debug)]
+pub struct Npc {
+    pub name: &'static str,
+    pub location: Location,
+    pub nodes: &'static [DialogueNode],
+}
+
+impl Npc {
+    pub fn at(location: &Location) -> Option<&'static Npc> {
+        NPCS.iter().find(|npc| npc.location == *location)
+    }
+}
+
+const LEAVE: Choice = Choice {
+    text: "Take your leave",
+    conditions: &[],
+    outcomes: &[],
+    remember: None,
+    next: None,
+};
+
+pub const NPCS: &[Npc] = &[
+    Npc {
+        name: "Aldric the Hermit",
+        location: Location::DarkForest,
+        nodes: &[
+            DialogueNode {
+                line: "A stooped hermit peers at you from beneath a mossy hood.\n\
+                       'Few walk these woods by choice. What brings you, stranger?'",
+                choices: &[
+                    Choice {
+                        text: "Ask about the forest",
+                        conditions: &[],
+                        outcomes: &[Outcome::Lore(
+                            "'The wolves grow bolder every moon. Something in the east drives them.'",
+                        )],
+                        remember: None,
+                        next: Some(1),
+                    },
+                    Choice {
+                        text: "Study the runes carved into his staff",
+                        conditions: &[Condition::Class(Class::Mage)],
+                        outcomes: &[Outcome::Lore(
+                            "The runes are no hermit's charms - they are a cultist's binding sigils.",
+                        )],
+                        remember: Some("hermit_sigils"),
+                        next: Some(0),
+                    },
+                    Choice {
+                        text: "Buy a Health Potion",
+                        conditions: &[Condition::MinGold(30)],
+                        outcomes: &[Outcome::Buy {
+                            item: "Health Potion",
+                            value: 50,
+                            cost: 30,
+                        }],
+                        remember: None,
+                        next: Some(0),
+                    },
+                    Choice {
+                        text: "Offer to help with the wolves",
+                        conditions: &[],
+                        outcomes: &[Outcome::Quest("Wolf Cull")],
+                        remember: None,
+                        next: None,
+                    },
+                    Choice {
+                        text: "Accuse him of serving the cult",
+                        conditions: &[Condition::Remembers("hermit_sigils")],
+                        outcomes: &[
+                            Outcome::Lore("The hermit's kindly face twists. 'Clever. Too clever.'"),
+                            Outcome::Fight(EnemyKind::Cultist),
+                        ],
+                        remember: None,
+                        next: None,
+                    },
+                    LEAVE,
+                ],
+            },
+            DialogueNode {
+                line: "'Before the wyrms, a shrine stood in the ruins to the north.\n\
+                       The runes there still remember what the living forgot.'",
+                choices: &[
+                    Choice {
+                        text: "Ask something else",
+                        conditions: &[],
+                        outcomes: &[],
+                        remember: None,
+                        next: Some(0),
+                    },
+                    LEAVE,
+                ],
+            },
+        ],
+    },
+    Npc {
+        name: "Sister Maren's Ghost",
+        location: Location::HauntedCrypt,
+        nodes: &[
+            DialogueNode {
+                line: "A pale woman in tattered vestments drifts out of a tomb.\n\
+                       'You can see me? Then hear me, living one.'",
+                choices: &[
+                    Choice {
+                        text: "Ask who she was",
+                        conditions: &[],
+                        outcomes: &[],
+                        remember: Some("met_maren"),
+                        next: Some(1),
+                    },
+                    Choice {
+                        text: "Read the last rites from the Mystic Tome",
+                        conditions: &[
+                            Condition::HasItem("Mystic Tome"),
+                            Condition::Forgets(MAREN_AT_PEACE),
+                        ],
+                        outcomes: &[
+                            Outcome::Lore("Maren's form softens into light. 'Take this. I have no more need of it.'"),
+                            Outcome::Gift {
+                                item: "Elven Amulet",
+                                value: 200,
+                            },
+                        ],
+                        remember: Some(MAREN_AT_PEACE),
+                        next: None,
+                    },
+                    Choice {
+                        text: "Ask how to help her rest",
+                        conditions: &[Condition::Remembers("met_maren")],
+                        outcomes: &[Outcome::Quest("Rites of the Forgotten")],
+                        remember: None,
+                        next: None,
+                    },
+                    LEAVE,
+                ],
+            },
+            DialogueNode {
+                line: "'I kept this crypt when the bells still rang. Then the wyrms came,\n\
+                       and the dead stopped sleeping. Pray for us, if you still can.'",
+                choices: &[
+                    Choice {
+                        text: "Ask something else",
+                        conditions: &[],
+                        outcomes: &[],
+                        remember: None,
+                        next: Some(0),
+                    },
+                    LEAVE,
+                ],
+            },
+        ],
+    },
+    Npc {
+        name: "Brannoc the Peddler",
+        location: Location::MountainPass,
+        nodes: &[DialogueNode {
+            line: "A peddler sits on an overturned cart, stamping his feet against the cold.\n\
+                   'Buying or selling? Either way, be quick about it.'",
+            choices: &[
+                Choice {
+                    text: "Buy an Energy Potion",
+                    conditions: &[Condition::MinGold(35)],
+                    outcomes: &[Outcome::Buy {
+                        item: "Energy Potion",
+                        value: 40,
+                        cost: 35,
+                    }],
+                    remember: None,
+                    next: Some(0),
+                },
+                Choice {
+                    text: "Buy a Fire Flask",
+                    conditions: &[Condition::MinGold(60)],
+                    outcomes: &[Outcome::Buy {
+                        item: "Fire Flask",
+                        value: 70,
+                        cost: 60,
+                    }],
+                    remember: None,
+                    next: Some(0),
+                },
+                Choice {
+                    text: "Sell a Gem",
+                    conditions: &[Condition::HasItem("Gem")],
+                    outcomes: &[Outcome::Sell {
+                        item: "Gem",
+                        price: 110,
+                    }],
+                    remember: None,
+                    next: Some(0),
+                },
+                Choice {
+                    text: "Sell a Dragon Scale",
+                    conditions: &[Condition::HasItem("Dragon Scale")],
+                    outcomes: &[Outcome::Sell {
+                        item: "Dragon Scale",
+                        price: 300,
+                    }],
+                    remember: None,
+                    next: Some(0),
+                },
+                Choice {
+                    text: "Lift a coin pouch while he counts his stock",
+                    conditions: &[
+                        Condition::Class(Class::Rogue),
+                        Condition::Forgets("robbed_peddler"),
+                    ],
+                    outcomes: &[Outcome::Gift {
+                        item: "Gold Coin Pouch",
+                        value: 100,
+                    }],
+                    remember: Some("robbed_peddler"),
+                    next: None,
+                },
+                Choice {
+                    text: "Share a drink and ask for rumors",
+                    conditions: &[Condition::Class(Class::Warrior)],
+                    outcomes: &[Outcome::Lore(
+                        "'Orcs have been raiding the passes. Someone's paying good coin for their heads.'",
+                    ),
+                    Outcome::Quest("The Orc Menace")],
+                    remember: None,
+                    next: Some(0),
+                },
+                LEAVE,
+            ],
+        }],
+    },
+];
@@ -0,0 +1,314 @@
+use std::collections::VecDeque;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::narrative::Location;
+
+const GRID_WIDTH: i32 = 5;
+const GRID_HEIGHT: i32 = 4;
+const BASE_ROOMS: u32 = 6;
+const MAX_ROOMS: u32 = 12;
+
+// Each floor down takes on the character of a different region
+const THEMES: [Location; 5] = [
+    Location::AbandonedRuins,
+    Location::HauntedCrypt,
+    Location::ShadowMarsh,
+    Location::FrozenWastes,
+    Location::VolcanicCavern,
+];
+
+// (kind, weight) for every room that isn't the entrance or the boss
+const ROOM_TABLE: [(RoomKind, u32); 4] = [
+    (RoomKind::Combat, 50),
+    (RoomKind::Treasure, 20),
+    (RoomKind::Trap, 15),
+    (RoomKind::Shrine, 15),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoomKind {
+    Entrance,
+    Combat,
+    Treasure,
+    Trap,
+    Shrine,
+    Boss,
+}
+
+impl RoomKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RoomKind::Entrance => "Entrance",
+            RoomKind::Combat => "Guarded Chamber",
+            RoomKind::Treasure => "Treasure Vault",
+            RoomKind::Trap => "Trapped Hall",
+            RoomKind::Shrine => "Forgotten Shrine",
+            RoomKind::Boss => "Wyrm's Den",
+        }
+    }
+
+    // How a room looks on the map once the player has been inside
+    fn symbol(&self) -> char {
+        match self {
+            RoomKind::Entrance => 'E',
+            RoomKind::Shrine => '+',
+            RoomKind::Boss => 'B',
+            _ => '.',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Direction::North => "North",
+            Direction::South => "South",
+            Direction::East => "East",
+            Direction::West => "West",
+        }
+    }
+
+    fn offset(&self) -> (i32, i32) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::South => (0, 1),
+            Direction::East => (1, 0),
+            Direction::West => (-1, 0),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Room {
+    pub kind: RoomKind,
+    x: i32,
+    y: i32,
+    visited: bool,
+    cleared: bool,
+}
+
+impl Room {
+    fn new(kind: RoomKind, x: i32, y: i32) -> Self {
+        Room {
+            kind,
+            x,
+            y,
+            visited: false,
+            cleared: false,
+        }
+    }
+
+    pub fn is_cleared(&self) -> bool {
+        self.cleared
+    }
+}
+
+// One level of the dungeon: rooms on a grid, joined wherever they touch
+#[derive(Debug)]
+pub struct Floor {
+    depth: u32,
+    rooms: Vec<Room>,
+    current: usize,
+}
+
+impl Floor {
+    // The layout depends only on the seed and depth, never on how fights went
+    pub fn generate(seed: u64, depth: u32) -> Self {
+        let mut rng =
+            StdRng::seed_from_u64(seed ^ (depth as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        let room_count = (BASE_ROOMS + depth).min(MAX_ROOMS) as usize;
+
+        let mut entrance = Room::new(
+            RoomKind::Entrance,
+            rng.random_range(0..GRID_WIDTH),
+            rng.random_range(0..GRID_HEIGHT),
+        );
+        entrance.visited = true;
+        entrance.cleared = true;
+        let mut floor = Floor {
+            depth,
+            rooms: vec![entrance],
+            current: 0,
+        };
+
+        // Grow the floor outward from rooms already dug
+        while floor.rooms.len() < room_count {
+            let from = &floor.rooms[rng.random_range(0..floor.rooms.len())];
+            let (dx, dy) = Direction::ALL[rng.random_range(0..4)].offset();
+            let (x, y) = (from.x + dx, from.y + dy);
+            let in_bounds = (0..GRID_WIDTH).contains(&x) && (0..GRID_HEIGHT).contains(&y);
+            if in_bounds && floor.room_at(x, y).is_none() {
+                let kind = roll_room(&mut rng);
+                floor.rooms.push(Room::new(kind, x, y));
+            }
+        }
+
+        // The boss waits in the room farthest from the entrance
+        let boss = floor.farthest_room();
+        floor.rooms[boss].kind = RoomKind::Boss;
+        floor
+    }
+
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    pub fn theme(&self) -> Location {
+        THEMES[(self.depth as usize - 1) % THEMES.len()]
+    }
+
+    pub fn current_room(&self) -> &Room {
+        &self.rooms[self.current]
+    }
+
+    pub fn exits(&self) -> Vec<Direction> {
+        let room = self.current_room();
+        Direction::ALL
+            .into_iter()
+            .filter(|direction| {
+                let (dx, dy) = direction.offset();
+                self.room_at(room.x + dx, room.y + dy).is_some()
+            })
+            .collect()
+    }
+
+    // Moves through an exit and returns the room entered
+    pub fn step(&mut self, direction: Direction) -> &Room {
+        let room = self.current_room();
+        let (dx, dy) = direction.offset();
+        if let Some(next) = self.room_at(room.x + dx, room.y + dy) {
+            self.current = next;
+            self.rooms[next].visited = true;
+        }
+        self.current_room()
+    }
+
+    pub fn clear_current(&mut self) {
+        self.rooms[self.current].cleared = true;
+    }
+
+    // ASCII map of every room the player has seen or stands beside
+    pub fn render(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        for y in 0..GRID_HEIGHT {
+            let mut rooms = String::from("  ");
+            let mut doors = String::from("  ");
+            for x in 0..GRID_WIDTH {
+                let shown = self.room_at(x, y).filter(|&index| self.is_revealed(index));
+                match shown {
+                    Some(index) => rooms.push_str(&format!("[{}]", self.glyph(index))),
+                    None => rooms.push_str("   "),
+                }
+                let east = self
+                    .room_at(x + 1, y)
+                    .filter(|&index| self.is_revealed(index));
+                rooms.push(if shown.is_some() && east.is_some() {
+                    '-'
+                } else {
+                    ' '
+                });
+                let south = self
+                    .room_at(x, y + 1)
+                    .filter(|&index| self.is_revealed(index));
+                doors.push_str(if shown.is_some() && south.is_some() {
+                    " | "
+                } else {
+                    "   "
+                });
+                doors.push(' ');
+            }
+            lines.push(rooms.trim_end().to_string());
+            if y + 1 < GRID_HEIGHT {
+                lines.push(doors.trim_end().to_string());
+            }
+        }
+        // Drop the unexplored rows above and below what has been seen
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        let first = lines.iter().position(|line| !line.is_empty()).unwrap_or(0);
+        lines.split_off(first)
+    }
+
+    fn glyph(&self, index: usize) -> char {
+        let room = &self.rooms[index];
+        if index == self.current {
+            '@'
+        } else if room.visited {
+            room.kind.symbol()
+        } else if room.kind == RoomKind::Boss {
+            'B' // Its presence can be felt through the walls
+        } else {
+            '?'
+        }
+    }
+
+    fn is_revealed(&self, index: usize) -> bool {
+        let room = &self.rooms[index];
+        room.visited
+            || Direction::ALL.iter().any(|direction| {
+                let (dx, dy) = direction.offset();
+                self.room_at(room.x + dx, room.y + dy)
+                    .is_some_and(|neighbor| self.rooms[neighbor].visited)
+            })
+    }
+
+    fn room_at(&self, x: i32, y: i32) -> Option<usize> {
+        self.rooms
+            .iter()
+            .position(|room| room.x == x && room.y == y)
+    }
+
+    fn farthest_room(&self) -> usize {
+        let mut distance = vec![None; self.rooms.len()];
+        distance[0] = Some(0);
+        let mut queue = VecDeque::from([0]);
+        let mut farthest = 0;
+        while let Some(index) = queue.pop_front() {
+            let room = &self.rooms[index];
+            let steps = distance[index].unwrap_or(0);
+            if steps > distance[farthest].unwrap_or(0) {
+                farthest = index;
+            }
+            for direction in Direction::ALL {
+                let (dx, dy) = direction.offset();
+                if let Some(next) = self.room_at(room.x + dx, room.y + dy) {
+                    if distance[next].is_none() {
+                        distance[next] = Some(steps + 1);
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        farthest
+    }
+}
+
+fn roll_room(rng: &mut StdRng) -> RoomKind {
+    let total: u32 = ROOM_TABLE.iter().map(|(_, weight)| weight).sum();
+    let mut roll = rng.random_range(1..=total);
+    for (kind, weight) in ROOM_TABLE {
+        if roll <= weight {
+            return kind;
+        }
+        roll -= weight;
+    }
+    RoomKind::Combat
+}
@@ -1,4 +1,5 @@
-use crate::character::traits::{Character, Combat, Fighter, ResourcePool};
+use crate::character::damage::{Affinity, DamageType};
+use crate::character::traits::{Character, Combat, ResourcePool};
 
 const BASE_HEALTH: u32 = 50;
 const HEALTH_PER_LEVEL: u32 = 15;
@@ -7,9 +8,149 @@ const ATTACK_PER_LEVEL: u32 = 3;
 // Enemies don't use resources, so they have infinite "rage" conceptually
 const ENEMY_RAGE: u32 = 999;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnemyKind {
+    Goblin,
+    Orc,
+    Dragon,
+    DragonWhelp,
+    DireWolf,
+    Cultist,
+    StoneGolem,
+    Harpy,
+    Skeleton,
+    Ghoul,
+    Specter,
+    FireElemental,
+    MagmaHound,
+    IceWraith,
+    FrostTroll,
+    BogHorror,
+    MarshHag,
+}
+
+impl EnemyKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EnemyKind::Goblin => "Goblin",
+            EnemyKind::Orc => "Orc",
+            EnemyKind::Dragon => "Dragon",
+            EnemyKind::DragonWhelp => "Dragon Whelp",
+            EnemyKind::DireWolf => "Dire Wolf",
+            EnemyKind::Cultist => "Cultist",
+            EnemyKind::StoneGolem => "Stone Golem",
+            EnemyKind::Harpy => "Harpy",
+            EnemyKind::Skeleton => "Skeleton",
+            EnemyKind::Ghoul => "Ghoul",
+            EnemyKind::Specter => "Specter",
+            EnemyKind::FireElemental => "Fire Elemental",
+            EnemyKind::MagmaHound => "Magma Hound",
+            EnemyKind::IceWraith => "Ice Wraith",
+            EnemyKind::FrostTroll => "Frost Troll",
+            EnemyKind::BogHorror => "Bog Horror",
+            EnemyKind::MarshHag => "Marsh Hag",
+        }
+    }
+
+    pub fn is_undead(&self) -> bool {
+        matches!(
+            self,
+            EnemyKind::Skeleton | EnemyKind::Ghoul | EnemyKind::Specter
+        )
+    }
+
+    // (bonus health, bonus attack) on top of the level-scaled base stats
+    fn stat_bonus(&self) -> (u32, u32) {
+        match self {
+            EnemyKind::Goblin => (0, 0),
+            EnemyKind::Orc => (20, 5),
+            EnemyKind::Dragon => (50, 15),
+            EnemyKind::DragonWhelp => (0, 0),
+            EnemyKind::DireWolf => (5, 4),
+            EnemyKind::Cultist => (0, 6),
+            EnemyKind::StoneGolem => (45, 2),
+            EnemyKind::Harpy => (0, 5),
+            EnemyKind::Skeleton => (10, 3),
+            EnemyKind::Ghoul => (20, 4),
+            EnemyKind::Specter => (5, 8),
+            EnemyKind::FireElemental => (15, 9),
+            EnemyKind::MagmaHound => (20, 6),
+            EnemyKind::IceWraith => (10, 8),
+            EnemyKind::FrostTroll => (40, 7),
+            EnemyKind::BogHorror => (35, 5),
+            EnemyKind::MarshHag => (10, 7),
+        }
+    }
+
+    pub fn attack_flavor(&self) -> &'static str {
+        match self {
+            EnemyKind::Goblin => "stabs at you with a rusty shiv",
+            EnemyKind::Orc => "swings a brutal war axe",
+            EnemyKind::Dragon => "rakes you with obsidian claws",
+            EnemyKind::DragonWhelp => "snaps at you with needle-sharp teeth",
+            EnemyKind::DireWolf => "lunges and sinks its fangs in",
+            EnemyKind::Cultist => "hurls a bolt of profane fire",
+            EnemyKind::StoneGolem => "slams down a fist of granite",
+            EnemyKind::Harpy => "dives shrieking with razor talons",
+            EnemyKind::Skeleton => "hacks with a notched, ancient sword",
+            EnemyKind::Ghoul => "claws at you with filthy nails",
+            EnemyKind::Specter => "passes through you with a chilling wail",
+            EnemyKind::FireElemental => "engulfs you in a roaring gout of flame",
+            EnemyKind::MagmaHound => "bites with jaws of molten rock",
+            EnemyKind::IceWraith => "lashes out with a freezing touch",
+            EnemyKind::FrostTroll => "batters you with an ice-crusted club",
+            EnemyKind::BogHorror => "drags you down into the sucking mire",
+            EnemyKind::MarshHag => "spits a curse of rotting bile",
+        }
+    }
+
+    pub fn damage_type(&self) -> DamageType {
+        match self {
+            EnemyKind::Dragon
+            | EnemyKind::DragonWhelp
+            | EnemyKind::FireElemental
+            | EnemyKind::MagmaHound => DamageType::Fire,
+            EnemyKind::IceWraith | EnemyKind::FrostTroll => DamageType::Frost,
+            EnemyKind::Cultist | EnemyKind::Ghoul | EnemyKind::Specter | EnemyKind::MarshHag => {
+                DamageType::Shadow
+            }
+            _ => DamageType::Physical,
+        }
+    }
+
+    pub fn affinity(&self, damage_type: DamageType) -> Affinity {
+        use DamageType::*;
+        match (self, damage_type) {
+            (EnemyKind::Dragon | EnemyKind::DragonWhelp, Fire) => Affinity::Resistant,
+            (EnemyKind::Dragon | EnemyKind::DragonWhelp, Frost) => Affinity::Weak,
+            (EnemyKind::DireWolf, Fire) => Affinity::Weak,
+            (EnemyKind::Cultist, Shadow) => Affinity::Resistant,
+            (EnemyKind::Cultist, Holy) => Affinity::Weak,
+            (EnemyKind::StoneGolem, Physical | Fire) => Affinity::Resistant,
+            (EnemyKind::StoneGolem, Frost) => Affinity::Weak,
+            (EnemyKind::Harpy, Fire) => Affinity::Weak,
+            (EnemyKind::Skeleton, Frost | Shadow) => Affinity::Resistant,
+            (EnemyKind::Skeleton, Holy) => Affinity::Weak,
+            (EnemyKind::Ghoul, Shadow) => Affinity::Resistant,
+            (EnemyKind::Ghoul, Holy | Fire) => Affinity::Weak,
+            (EnemyKind::Specter, Physical) => Affinity::Resistant,
+            (EnemyKind::Specter, Holy) => Affinity::Weak,
+            (EnemyKind::FireElemental | EnemyKind::MagmaHound, Fire) => Affinity::Resistant,
+            (EnemyKind::FireElemental | EnemyKind::MagmaHound, Frost) => Affinity::Weak,
+            (EnemyKind::IceWraith | EnemyKind::FrostTroll, Frost) => Affinity::Resistant,
+            (EnemyKind::IceWraith | EnemyKind::FrostTroll, Fire) => Affinity::Weak,
+            (EnemyKind::BogHorror, Physical) => Affinity::Resistant,
+            (EnemyKind::BogHorror, Fire) => Affinity::Weak,
+            (EnemyKind::MarshHag, Holy) => Affinity::Weak,
+            _ => Affinity::Neutral,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Enemy {
     name: String,
+    kind: EnemyKind,
     health: u32,
     max_health: u32,
     level: u32,
@@ -17,35 +158,46 @@ pub struct Enemy {
 }
 
 impl Enemy {
-    pub fn new(name: String, level: u32) -> Self {
-        let max_health = BASE_HEALTH + (level * HEALTH_PER_LEVEL);
+    pub fn new(kind: EnemyKind, level: u32) -> Self {
+        let (bonus_health, bonus_attack) = kind.stat_bonus();
+        let max_health = BASE_HEALTH + (level * HEALTH_PER_LEVEL) + bonus_health;
         Enemy {
-            name,
+            name: kind.name().to_string(),
+            kind,
             health: max_health,
             max_health,
             level,
-            attack_power: BASE_ATTACK + (level * ATTACK_PER_LEVEL),
+            attack_power: BASE_ATTACK + (level * ATTACK_PER_LEVEL) + bonus_attack,
         }
     }
 
-    pub fn goblin(level: u32) -> Self {
-        Self::new(String::from("Goblin"), level)
+    // Boss variant: a named, tougher version of the base creature
+    pub fn empowered(mut self, name: &str, bonus_health: u32, bonus_attack: u32) -> Self {
+        self.name = name.to_string();
+        self.max_health += bonus_health;
+        self.health = self.max_health;
+        self.attack_power += bonus_attack;
+        self
+    }
+
+    // Summoned add: a frailer version of the base creature
+    pub fn into_minion(mut self) -> Self {
+        self.max_health /= 2;
+        self.health = self.max_health;
+        self.attack_power /= 2;
+        self
     }
 
-    pub fn orc(level: u32) -> Self {
-        let mut enemy = Self::new(String::from("Orc"), level);
-        enemy.max_health += 20;
-        enemy.health = enemy.max_health;
-        enemy.attack_power += 5;
-        enemy
+    pub fn enrage(&mut self, bonus_attack: u32) {
+        self.attack_power += bonus_attack;
     }
 
-    pub fn dragon(level: u32) -> Self {
-        let mut enemy = Self::new(String::from("Dragon"), level);
-        enemy.max_health += 50;
-        enemy.health = enemy.max_health;
-        enemy.attack_power += 15;
-        enemy
+    pub fn kind(&self) -> EnemyKind {
+        self.kind
+    }
+
+    pub fn attack_flavor(&self) -> &'static str {
+        self.kind.attack_flavor()
     }
 }
 
@@ -69,6 +221,18 @@ impl Character for Enemy {
     fn set_health(&mut self, health: u32) {
         self.health = health;
     }
+
+    fn get_experience(&self) -> u32 {
+        0
+    }
+
+    fn set_experience(&mut self, _experience: u32) {
+        // Enemies don't gain experience
+    }
+
+    fn level_up(&mut self) {
+        // Enemy levels are fixed when they spawn
+    }
 }
 
 impl ResourcePool for Enemy {
@@ -99,6 +263,10 @@ impl Combat for Enemy {
         None
     }
 
+    fn special_damage(&self) -> u32 {
+        0
+    }
+
     fn special_ability_cost(&self) -> u32 {
         0
     }
@@ -110,6 +278,20 @@ impl Combat for Enemy {
     fn can_use_special(&self) -> bool {
         false
     }
-}
 
-impl Fighter for Enemy {}
+    fn defend_resource_gain(&self) -> u32 {
+        0
+    }
+
+    fn attack_damage_type(&self) -> DamageType {
+        self.kind.damage_type()
+    }
+
+    fn special_damage_type(&self) -> DamageType {
+        self.kind.damage_type()
+    }
+
+    fn affinity(&self, damage_type: DamageType) -> Affinity {
+        self.kind.affinity(damage_type)
+    }
+}
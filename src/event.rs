@@ -0,0 +1,44 @@
+use crate::enemy::EnemyKind;
+use crate::inventory::consumable::Consumable;
+use crate::narrative::{Location, LocationAction};
+
+// What the player chose to do on a turn in battle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombatAction {
+    Attack,
+    Special,
+    Defend,
+    Item,
+    Summon,
+}
+
+// Gold handed over for deeds versus gold picked up along the way
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoldSource {
+    Earned,
+    Found,
+}
+
+// Things that happen during a run that other systems (quests, achievements, stats) react to
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    EncounterStarted(u32),
+    LocationVisited(Location),
+    CombatActionTaken(CombatAction),
+    DamageDealt { amount: u32, critical: bool },
+    DamageTaken { amount: u32, critical: bool },
+    BattleWon,
+    EnemySlain(EnemyKind),
+    ItemFound(String),
+    ItemUsed(&'static str),
+    TalentLearned(&'static str), // Talent id
+    GoldGained { amount: u32, source: GoldSource },
+    ActionTaken(Location, LocationAction),
+}
+
+impl GameEvent {
+    pub fn is_potion_used(&self) -> bool {
+        matches!(self, GameEvent::ItemUsed(name)
+            if Consumable::find(name).is_some_and(Consumable::is_potion))
+    }
+}
@@ -0,0 +1,81 @@
+use crate::character::DamageType;
+use crate::narrative::Location;
+
+// The lay of the land turns against everyone fighting on it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hazard {
+    Scorching,
+    Blizzard,
+    Miasma,
+    Hallowed,
+}
+
+impl Hazard {
+    pub fn at(location: &Location) -> Option<Hazard> {
+        match location {
+            Location::VolcanicCavern => Some(Hazard::Scorching),
+            Location::FrozenWastes => Some(Hazard::Blizzard),
+            Location::ShadowMarsh => Some(Hazard::Miasma),
+            Location::HauntedCrypt => Some(Hazard::Hallowed),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Hazard::Scorching => "Scorching Heat",
+            Hazard::Blizzard => "Blizzard",
+            Hazard::Miasma => "Poison Miasma",
+            Hazard::Hallowed => "Hallowed Ground",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Hazard::Scorching => "🌋",
+            Hazard::Blizzard => "🌨️",
+            Hazard::Miasma => "🦠",
+            Hazard::Hallowed => "🕯️",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Hazard::Scorching => "Waves of heat sear every combatant at the end of each turn.",
+            Hazard::Blizzard => "Numbing cold saps your agility, blunting swift strikes.",
+            Hazard::Miasma => "Foul vapors rise from the bog; wounds here may fester with poison.",
+            Hazard::Hallowed => "Old blessings linger in the stones, strengthening holy power.",
+        }
+    }
+
+    // Fire damage dealt to everyone at the end of each turn
+    pub fn burn(&self) -> u32 {
+        match self {
+            Hazard::Scorching => 4,
+            _ => 0,
+        }
+    }
+
+    // Agility the cold takes from agile fighters for the rest of the battle
+    pub fn agility_loss(&self) -> u32 {
+        match self {
+            Hazard::Blizzard => 6,
+            _ => 0,
+        }
+    }
+
+    // Percent chance each enemy turn that the player is poisoned
+    pub fn poison_chance(&self) -> u32 {
+        match self {
+            Hazard::Miasma => 20,
+            _ => 0,
+        }
+    }
+
+    pub fn damage_modifier(&self, damage_type: DamageType) -> f32 {
+        match (self, damage_type) {
+            (Hazard::Hallowed, DamageType::Holy) => 1.3,
+            _ => 1.0,
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use rand::Rng;
+
+use super::bag::Inventory;
+use super::item::Item;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AffixStat {
+    Might,
+    Guard,
+    Lifesteal,
+}
+
+impl AffixStat {
+    pub fn describe(&self, bonus: u32) -> String {
+        match self {
+            AffixStat::Might => format!("+{}% damage dealt", bonus),
+            AffixStat::Guard => format!("-{}% damage taken", bonus),
+            AffixStat::Lifesteal => format!("heal {}% of damage dealt", bonus),
+        }
+    }
+
+    // Smallest roll at encounter 0
+    fn base_roll(&self) -> u32 {
+        match self {
+            AffixStat::Might => 5,
+            AffixStat::Guard => 4,
+            AffixStat::Lifesteal => 3,
+        }
+    }
+
+    // Carried gear stacks, but only up to a point
+    fn cap(&self) -> u32 {
+        match self {
+            AffixStat::Might => 50,
+            AffixStat::Guard => 40,
+            AffixStat::Lifesteal => 25,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Affix {
+    pub name: &'static str,
+    pub stat: AffixStat,
+    pub bonus: u32,
+}
+
+const PREFIXES: &[(&str, AffixStat)] = &[
+    ("Vampiric", AffixStat::Lifesteal),
+    ("Brutal", AffixStat::Might),
+    ("Tempered", AffixStat::Guard),
+    ("Searing", AffixStat::Might),
+];
+
+const SUFFIXES: &[(&str, AffixStat)] = &[
+    ("of Embers", AffixStat::Might),
+    ("of the Bulwark", AffixStat::Guard),
+    ("of Leeching", AffixStat::Lifesteal),
+    ("of Ashen Scales", AffixStat::Guard),
+];
+
+impl Affix {
+    // Rolls scale with how deep into the journey the item was found
+    fn roll(table: &[(&'static str, AffixStat)], depth: u32, rng: &mut impl Rng) -> Self {
+        let (name, stat) = table[rng.random_range(0..table.len())];
+        let base = stat.base_roll();
+        let bonus = rng.random_range(base..=base + depth / 2).min(base * 4);
+        Affix { name, stat, bonus }
+    }
+
+    pub fn roll_prefix(depth: u32, rng: &mut impl Rng) -> Self {
+        Affix::roll(PREFIXES, depth, rng)
+    }
+
+    pub fn roll_suffix(depth: u32, rng: &mut impl Rng) -> Self {
+        Affix::roll(SUFFIXES, depth, rng)
+    }
+}
+
+// Combined bonus from every affix in the bag, clamped to the stat's cap
+pub fn total_bonus(inventory: &Inventory, stat: AffixStat) -> u32 {
+    let total: u32 = inventory
+        .items()
+        .flat_map(Item::affixes)
+        .filter(|affix| affix.stat == stat)
+        .map(|affix| affix.bonus)
+        .sum();
+    total.min(stat.cap())
+}
@@ -0,0 +1,202 @@
+use super::item::{Item, ItemKind};
+use super::rarity::Rarity;
+
+pub const BAG_CAPACITY: usize = 12;
+pub const MAX_STACK: u32 = 10;
+
+// Identical items share one slot, up to MAX_STACK of them
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stack {
+    item: Item,
+    count: u32,
+}
+
+impl Stack {
+    pub fn item(&self) -> &Item {
+        &self.item
+    }
+
+    // "Health Potion x3" for stacks, plain name for single items
+    pub fn label(&self) -> String {
+        if self.count > 1 {
+            format!("{} x{}", self.item.display_name(), self.count)
+        } else {
+            self.item.display_name()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Kind,
+    Rarity,
+    Value,
+    Name,
+}
+
+impl SortKey {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SortKey::Kind => "Kind",
+            SortKey::Rarity => "Rarity",
+            SortKey::Value => "Value",
+            SortKey::Name => "Name",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            SortKey::Kind => SortKey::Rarity,
+            SortKey::Rarity => SortKey::Value,
+            SortKey::Value => SortKey::Name,
+            SortKey::Name => SortKey::Kind,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    All,
+    Kind(ItemKind),
+    RareOrBetter,
+}
+
+impl Filter {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Filter::All => "All",
+            Filter::Kind(kind) => kind.name(),
+            Filter::RareOrBetter => "Rare+",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Filter::All => Filter::Kind(ItemKind::Consumable),
+            Filter::Kind(ItemKind::Consumable) => Filter::Kind(ItemKind::Gear),
+            Filter::Kind(ItemKind::Gear) => Filter::Kind(ItemKind::Material),
+            Filter::Kind(ItemKind::Material) => Filter::Kind(ItemKind::Treasure),
+            Filter::Kind(ItemKind::Treasure) => Filter::RareOrBetter,
+            Filter::RareOrBetter => Filter::All,
+        }
+    }
+
+    fn matches(&self, item: &Item) -> bool {
+        match self {
+            Filter::All => true,
+            Filter::Kind(kind) => item.kind() == *kind,
+            Filter::RareOrBetter => item.rarity() >= Rarity::Rare,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Inventory {
+    stacks: Vec<Stack>,
+}
+
+impl Inventory {
+    pub fn new() -> Self {
+        Inventory { stacks: Vec::new() }
+    }
+
+    pub fn stacks(&self) -> &[Stack] {
+        &self.stacks
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
+    // Occupied slots
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+
+    // Every single item, counting each one in a stack
+    pub fn item_count(&self) -> u32 {
+        self.stacks.iter().map(|stack| stack.count).sum()
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = &Item> {
+        self.stacks
+            .iter()
+            .flat_map(|stack| std::iter::repeat_n(&stack.item, stack.count as usize))
+    }
+
+    pub fn count(&self, name: &str) -> u32 {
+        self.stacks
+            .iter()
+            .filter(|stack| stack.item.name() == name)
+            .map(|stack| stack.count)
+            .sum()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.count(name) > 0
+    }
+
+    // Stores the item, handing it back if there is no room
+    pub fn add(&mut self, item: Item) -> Result<(), Item> {
+        if let Some(stack) = self
+            .stacks
+            .iter_mut()
+            .find(|stack| stack.item == item && stack.count < MAX_STACK)
+        {
+            stack.count += 1;
+            return Ok(());
+        }
+        if self.stacks.len() >= BAG_CAPACITY {
+            return Err(item);
+        }
+        self.stacks.push(Stack { item, count: 1 });
+        Ok(())
+    }
+
+    // Removes one item from the stack in the given slot
+    pub fn take(&mut self, slot: usize) -> Option<Item> {
+        let stack = self.stacks.get_mut(slot)?;
+        stack.count -= 1;
+        let item = stack.item.clone();
+        if stack.count == 0 {
+            self.stacks.remove(slot);
+        }
+        Some(item)
+    }
+
+    pub fn take_named(&mut self, name: &str) -> Option<Item> {
+        let slot = self
+            .stacks
+            .iter()
+            .position(|stack| stack.item.name() == name)?;
+        self.take(slot)
+    }
+
+    // Throws away the whole stack in the given slot
+    pub fn drop_slot(&mut self, slot: usize) -> Option<Stack> {
+        (slot < self.stacks.len()).then(|| self.stacks.remove(slot))
+    }
+
+    pub fn sort(&mut self, key: SortKey) {
+        match key {
+            SortKey::Kind => self.stacks.sort_by_key(|stack| stack.item.kind()),
+            SortKey::Rarity => self
+                .stacks
+                .sort_by_key(|stack| std::cmp::Reverse(stack.item.rarity())),
+            SortKey::Value => self
+                .stacks
+                .sort_by_key(|stack| std::cmp::Reverse(stack.item.value())),
+            SortKey::Name => self.stacks.sort_by_key(|stack| stack.item.display_name()),
+        }
+    }
+
+    // Slot indices of the stacks that pass the filter
+    pub fn visible(&self, filter: Filter) -> Vec<usize> {
+        self.stacks
+            .iter()
+            .enumerate()
+            .filter(|(_, stack)| filter.matches(&stack.item))
+            .map(|(slot, _)| slot)
+            .collect()
+    }
+}
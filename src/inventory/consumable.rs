@@ -0,0 +1,130 @@
+use crate::character::{DamageType, EffectKind, Fighter};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ItemEffect {
+    Heal(u32),
+    RestoreResource(u32),
+    Buff(EffectKind, u32),
+    // Removes every harmful effect
+    Cure,
+    DamageEnemy(u32, DamageType),
+}
+
+// Where an item can be used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Usable {
+    InCombat,
+    OutOfCombat,
+    Anywhere,
+}
+
+// Why an item can't be used right now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unusable {
+    CombatOnly,
+    NotInCombat,
+    NoEffect,
+}
+
+impl Unusable {
+    // Short tag for inventory listings
+    pub fn label(&self) -> &'static str {
+        match self {
+            Unusable::CombatOnly => "✗ Battle",
+            Unusable::NotInCombat => "✗ Camp",
+            Unusable::NoEffect => "✗ Full",
+        }
+    }
+
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Unusable::CombatOnly => "That can only be used in battle.",
+            Unusable::NotInCombat => "There's no time for that in the middle of a fight!",
+            Unusable::NoEffect => "That would have no effect right now.",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Consumable {
+    pub name: &'static str,
+    pub verb: &'static str,
+    pub usable: Usable,
+    pub effects: &'static [ItemEffect],
+}
+
+pub const CONSUMABLES: &[Consumable] = &[
+    Consumable {
+        name: "Health Potion",
+        verb: "drink",
+        usable: Usable::Anywhere,
+        effects: &[ItemEffect::Heal(30)],
+    },
+    Consumable {
+        name: "Energy Potion",
+        verb: "drink",
+        usable: Usable::Anywhere,
+        effects: &[ItemEffect::RestoreResource(40)],
+    },
+    Consumable {
+        name: "Purifying Salts",
+        verb: "breathe in",
+        usable: Usable::Anywhere,
+        effects: &[ItemEffect::Cure, ItemEffect::Heal(10)],
+    },
+    Consumable {
+        name: "Fire Flask",
+        verb: "hurl",
+        usable: Usable::InCombat,
+        effects: &[ItemEffect::DamageEnemy(35, DamageType::Fire)],
+    },
+    Consumable {
+        name: "Dragon Scale Armor",
+        verb: "strap on",
+        usable: Usable::OutOfCombat,
+        effects: &[ItemEffect::Buff(EffectKind::Armored, 10)],
+    },
+];
+
+impl Consumable {
+    pub fn find(name: &str) -> Option<&'static Consumable> {
+        CONSUMABLES
+            .iter()
+            .find(|consumable| consumable.name == name)
+    }
+
+    // Plain healing draughts, which the battle menu's Potion option reaches for
+    pub fn only_heals(&self) -> bool {
+        self.effects
+            .iter()
+            .all(|effect| matches!(effect, ItemEffect::Heal(_)))
+    }
+
+    // Anything that restores health or resource counts as a potion, whatever it is called
+    pub fn is_potion(&self) -> bool {
+        self.effects
+            .iter()
+            .any(|effect| matches!(effect, ItemEffect::Heal(_) | ItemEffect::RestoreResource(_)))
+    }
+
+    // An item is usable in the right place, and only if at least one effect would do something
+    pub fn check(&self, player: &dyn Fighter, in_combat: bool) -> Result<(), Unusable> {
+        match (self.usable, in_combat) {
+            (Usable::InCombat, false) => return Err(Unusable::CombatOnly),
+            (Usable::OutOfCombat, true) => return Err(Unusable::NotInCombat),
+            _ => {}
+        }
+
+        let helps = self.effects.iter().any(|effect| match effect {
+            ItemEffect::Heal(_) => player.get_health() < player.get_max_health(),
+            ItemEffect::RestoreResource(_) => player.get_resource() < player.get_max_resource(),
+            ItemEffect::Buff(..) | ItemEffect::DamageEnemy(..) => true,
+            ItemEffect::Cure => player.effects().iter().any(|e| e.kind.is_harmful()),
+        });
+        if helps {
+            Ok(())
+        } else {
+            Err(Unusable::NoEffect)
+        }
+    }
+}
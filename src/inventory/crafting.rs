@@ -0,0 +1,81 @@
+use super::bag::Inventory;
+use super::item::Item;
+
+#[derive(Debug)]
+pub struct Recipe {
+    pub name: &'static str,
+    pub value: u32,
+    pub ingredients: &'static [(&'static str, u32)],
+    // Strengthens the first ingredient's affixes by this much instead of making a new item
+    pub reforge: Option<u32>,
+}
+
+pub const RECIPES: &[Recipe] = &[
+    Recipe {
+        name: "Health Potion",
+        value: 50,
+        ingredients: &[("Healing Herb", 2)],
+        reforge: None,
+    },
+    Recipe {
+        name: "Energy Potion",
+        value: 40,
+        ingredients: &[("Glowing Mushroom", 1), ("Healing Herb", 1)],
+        reforge: None,
+    },
+    Recipe {
+        name: "Dragon Scale Armor",
+        value: 400,
+        ingredients: &[("Dragon Scale", 2)],
+        reforge: None,
+    },
+    Recipe {
+        name: "Reforge Enchanted Ring",
+        value: 150,
+        ingredients: &[("Enchanted Ring", 1), ("Gem", 2)],
+        reforge: Some(4),
+    },
+];
+
+impl Recipe {
+    pub fn can_craft(&self, inventory: &Inventory) -> bool {
+        self.ingredients
+            .iter()
+            .all(|&(name, needed)| inventory.count(name) >= needed)
+    }
+
+    // "2x Healing Herb (1/2)" style summary of what the recipe needs
+    pub fn requirements(&self, inventory: &Inventory) -> String {
+        self.ingredients
+            .iter()
+            .map(|&(name, needed)| {
+                format!(
+                    "{}x {} ({}/{})",
+                    needed,
+                    name,
+                    inventory.count(name),
+                    needed
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    // Consumes the ingredients and returns the crafted item
+    pub fn craft(&self, inventory: &mut Inventory) -> Option<Item> {
+        if !self.can_craft(inventory) {
+            return None;
+        }
+
+        let mut taken = Vec::new();
+        for &(name, needed) in self.ingredients {
+            for _ in 0..needed {
+                taken.extend(inventory.take_named(name));
+            }
+        }
+        match (self.reforge, taken.into_iter().next()) {
+            (Some(bonus), Some(gear)) => Some(gear.reforged(bonus, self.value)),
+            _ => Some(Item::new(self.name.to_string(), self.value)),
+        }
+    }
+}
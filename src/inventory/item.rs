@@ -1,26 +1,144 @@
+use rand::Rng;
+
+use crate::rng;
+
+use super::affix::Affix;
+use super::consumable::Consumable;
+use super::rarity::Rarity;
+
+// Weapons and jewellery that can roll affixes
+const GEAR: [&str; 3] = ["Silver Dagger", "Elven Amulet", "Enchanted Ring"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ItemKind {
+    Consumable,
+    Gear,
+    Material,
+    Treasure,
+}
+
+impl ItemKind {
+    fn of(name: &str, consumable: bool) -> Self {
+        const MATERIALS: [&str; 4] = ["Healing Herb", "Glowing Mushroom", "Gem", "Dragon Scale"];
+        if GEAR.contains(&name) || name == "Dragon Scale Armor" {
+            ItemKind::Gear
+        } else if consumable {
+            ItemKind::Consumable
+        } else if MATERIALS.contains(&name) {
+            ItemKind::Material
+        } else {
+            ItemKind::Treasure
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ItemKind::Consumable => "Consumables",
+            ItemKind::Gear => "Gear",
+            ItemKind::Material => "Materials",
+            ItemKind::Treasure => "Treasure",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Item {
     name: String,
     value: u32,
+    kind: ItemKind,
+    consumable: Option<&'static Consumable>,
+    rarity: Rarity,
+    prefix: Option<Affix>,
+    suffix: Option<Affix>,
 }
 
 impl Item {
     pub fn new(name: String, value: u32) -> Self {
-        Item { name, value }
+        let consumable = Consumable::find(&name);
+        Item {
+            kind: ItemKind::of(&name, consumable.is_some()),
+            consumable,
+            name,
+            value,
+            rarity: Rarity::from_value(value),
+            prefix: None,
+            suffix: None,
+        }
     }
 
+    // Base name, used to match quests, recipes and trades
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    // Full name with affixes, e.g. "Vampiric Silver Dagger of Embers"
+    pub fn display_name(&self) -> String {
+        let mut name = self.name.clone();
+        if let Some(prefix) = &self.prefix {
+            name = format!("{} {}", prefix.name, name);
+        }
+        if let Some(suffix) = &self.suffix {
+            name = format!("{} {}", name, suffix.name);
+        }
+        name
+    }
+
     pub fn value(&self) -> u32 {
         self.value
     }
 
-    pub fn random_loot() -> Self {
-        use rand::Rng;
-        let mut rng = rand::rng();
+    pub fn rarity(&self) -> Rarity {
+        self.rarity
+    }
+
+    pub fn kind(&self) -> ItemKind {
+        self.kind
+    }
+
+    // What happens when the item is used, if it can be used at all
+    pub fn consumable(&self) -> Option<&'static Consumable> {
+        self.consumable
+    }
+
+    pub fn affixes(&self) -> impl Iterator<Item = &Affix> {
+        self.prefix.iter().chain(self.suffix.iter())
+    }
+
+    // Gear found deeper in the journey is more likely to carry affixes, and rolls them stronger
+    fn with_affixes(mut self, depth: u32, rng: &mut impl Rng) -> Self {
+        let affix_chance = (30 + depth * 2).min(70);
+
+        if rng.random_range(1..=100) <= affix_chance {
+            self.prefix = Some(Affix::roll_prefix(depth, rng));
+        }
+        if rng.random_range(1..=100) <= affix_chance {
+            self.suffix = Some(Affix::roll_suffix(depth, rng));
+        }
+
+        let affixes: Vec<Affix> = self.affixes().copied().collect();
+        for affix in affixes {
+            self.value += affix.bonus * 10;
+            self.rarity = self.rarity.upgraded();
+        }
+        self
+    }
 
+    // Improves existing affixes and fills any empty affix slot with a fresh roll
+    pub fn reforged(mut self, bonus: u32, value: u32) -> Self {
+        for affix in self.prefix.iter_mut().chain(self.suffix.iter_mut()) {
+            affix.bonus += bonus;
+        }
+        let mut rng = rng::rng();
+        self.prefix
+            .get_or_insert_with(|| Affix::roll_prefix(0, &mut rng));
+        self.suffix
+            .get_or_insert_with(|| Affix::roll_suffix(0, &mut rng));
+        self.value += value;
+        self.rarity = self.rarity.upgraded();
+        self
+    }
+
+    pub fn random_loot(depth: u32, rng: &mut impl Rng) -> Self {
         let loot_roll = rng.random_range(1..=100);
 
         if loot_roll > 95 {
@@ -35,7 +153,7 @@ impl Item {
                 ("Mystic Tome", 220),
             ];
             let item = items[rng.random_range(0..items.len())];
-            Item::new(item.0.to_string(), item.1)
+            Item::gear(item.0, item.1, depth, rng)
         } else if loot_roll > 50 {
             // Common valuable items
             let items = [
@@ -45,21 +163,28 @@ impl Item {
                 ("Healing Herb", 60),
             ];
             let item = items[rng.random_range(0..items.len())];
-            Item::new(item.0.to_string(), item.1)
-        } else if loot_roll > 25 {
+            Item::gear(item.0, item.1, depth, rng)
+        } else if loot_roll > 30 {
             // Health potion
             Item::new("Health Potion".to_string(), 50)
+        } else if loot_roll > 25 {
+            // Alchemist's wares
+            let items = [("Fire Flask", 70), ("Purifying Salts", 60)];
+            let item = items[rng.random_range(0..items.len())];
+            Item::new(item.0.to_string(), item.1)
         } else {
             // Resource potion
             Item::new("Energy Potion".to_string(), 40)
         }
     }
 
-    pub fn is_health_potion(&self) -> bool {
-        self.name == "Health Potion"
-    }
-
-    pub fn is_energy_potion(&self) -> bool {
-        self.name == "Energy Potion"
+    // Jewellery and weapons can roll affixes; everything else is a plain item
+    fn gear(name: &str, value: u32, depth: u32, rng: &mut impl Rng) -> Self {
+        let item = Item::new(name.to_string(), value);
+        if GEAR.contains(&name) {
+            item.with_affixes(depth, rng)
+        } else {
+            item
+        }
     }
 }
@@ -1,3 +1,12 @@
+pub mod affix;
+pub mod bag;
+pub mod consumable;
+pub mod crafting;
 pub mod item;
+pub mod rarity;
 
+pub use affix::{total_bonus, AffixStat};
+pub use bag::{Filter, Inventory, SortKey, BAG_CAPACITY};
+pub use consumable::ItemEffect;
+pub use crafting::{Recipe, RECIPES};
 pub use item::Item;
@@ -0,0 +1,53 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl Rarity {
+    // Plain items are graded by what a merchant would pay for them
+    pub fn from_value(value: u32) -> Self {
+        if value >= 500 {
+            Rarity::Legendary
+        } else if value >= 150 {
+            Rarity::Rare
+        } else if value >= 60 {
+            Rarity::Uncommon
+        } else {
+            Rarity::Common
+        }
+    }
+
+    // Each affix on a piece of gear lifts it one grade; only artifacts are Legendary
+    pub fn upgraded(self) -> Self {
+        match self {
+            Rarity::Common => Rarity::Uncommon,
+            Rarity::Uncommon => Rarity::Rare,
+            Rarity::Rare | Rarity::Epic => Rarity::Epic,
+            Rarity::Legendary => Rarity::Legendary,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "Uncommon",
+            Rarity::Rare => "Rare",
+            Rarity::Epic => "Epic",
+            Rarity::Legendary => "Legendary",
+        }
+    }
+
+    pub fn banner(&self) -> &'static str {
+        match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "\x1b[92m★ UNCOMMON ★\x1b[0m",
+            Rarity::Rare => "\x1b[93m★★ RARE ★★\x1b[0m",
+            Rarity::Epic => "\x1b[94m★★★ EPIC ★★★\x1b[0m",
+            Rarity::Legendary => "\x1b[95m★★★★ LEGENDARY ★★★★\x1b[0m",
+        }
+    }
+}
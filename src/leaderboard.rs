@@ -0,0 +1,149 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::character::Class;
+use crate::storage;
+
+const LEADERBOARD_FILE: &str = "emberlight_leaderboard.json";
+const DAILY_FILE: &str = "emberlight_daily.json";
+
+// The game mode a run was played in; records from before delves were all adventures
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RunMode {
+    #[default]
+    Adventure,
+    Delve,
+}
+
+impl RunMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RunMode::Adventure => "Adventures",
+            RunMode::Delve => "Dungeon Delves",
+        }
+    }
+
+    // Short label for what a record's encounters count
+    pub fn tally(&self) -> &'static str {
+        match self {
+            RunMode::Adventure => "enc",
+            RunMode::Delve => "won",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            RunMode::Adventure => RunMode::Delve,
+            RunMode::Delve => RunMode::Adventure,
+        }
+    }
+}
+
+// The outcome of one finished run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub name: String,
+    pub class: Class,
+    pub encounters: u32, // Battles won, for delves
+    pub gold: u32,
+    pub cause: String,
+    pub seed: u64,
+    #[serde(default)]
+    pub talents: Vec<String>, // Learned talent ids, in the order they were learned
+    #[serde(default)]
+    pub mode: RunMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ranking {
+    Encounters,
+    Gold,
+}
+
+impl Ranking {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Ranking::Encounters => "Encounters",
+            Ranking::Gold => "Gold",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Ranking::Encounters => Ranking::Gold,
+            Ranking::Gold => Ranking::Encounters,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Leaderboard {
+    runs: Vec<RunRecord>,
+}
+
+impl Leaderboard {
+    // A missing file simply means nobody has finished a run yet
+    pub fn load() -> io::Result<Self> {
+        storage::read_json(LEADERBOARD_FILE)
+    }
+
+    // Refuses to write over a board that could not be read
+    pub fn record(run: RunRecord) -> io::Result<()> {
+        let mut leaderboard = Leaderboard::load()?;
+        leaderboard.runs.push(run);
+        let file = File::create(LEADERBOARD_FILE)?;
+        serde_json::to_writer_pretty(file, &leaderboard)?;
+        Ok(())
+    }
+
+    // Best runs of one mode first, optionally only those of one class
+    pub fn ranked(&self, ranking: Ranking, class: Option<Class>, mode: RunMode) -> Vec<&RunRecord> {
+        let mut runs: Vec<&RunRecord> = self
+            .runs
+            .iter()
+            .filter(|run| run.mode == mode)
+            .filter(|run| class.is_none_or(|class| run.class == class))
+            .collect();
+        match ranking {
+            Ranking::Encounters => runs.sort_by_key(|run| (run.encounters, run.gold)),
+            Ranking::Gold => runs.sort_by_key(|run| (run.gold, run.encounters)),
+        }
+        runs.reverse();
+        runs
+    }
+}
+
+// Daily Challenge results, kept apart from the Hall of Fame and grouped by date
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DailyLeaderboard {
+    days: BTreeMap<String, Vec<RunRecord>>,
+}
+
+impl DailyLeaderboard {
+    pub fn load() -> io::Result<Self> {
+        storage::read_json(DAILY_FILE)
+    }
+
+    pub fn record(date: &str, run: RunRecord) -> io::Result<()> {
+        let mut leaderboard = DailyLeaderboard::load()?;
+        leaderboard
+            .days
+            .entry(date.to_string())
+            .or_default()
+            .push(run);
+        let file = File::create(DAILY_FILE)?;
+        serde_json::to_writer_pretty(file, &leaderboard)?;
+        Ok(())
+    }
+
+    // The day's runs, furthest first
+    pub fn ranked(&self, date: &str) -> Vec<&RunRecord> {
+        let mut runs: Vec<&RunRecord> = self.days.get(date).into_iter().flatten().collect();
+        runs.sort_by_key(|run| (run.encounters, run.gold));
+        runs.reverse();
+        runs
+    }
+}
@@ -1,77 +1,211 @@
+mod achievement;
+mod ally;
+mod boss;
 mod character;
+mod chronicle;
+mod clock;
+mod daily;
+mod dialogue;
+mod dungeon;
 mod enemy;
+mod event;
+mod hazard;
 mod inventory;
+mod leaderboard;
+mod map;
 mod narrative;
+mod profile;
+mod quest;
+mod rng;
+mod stats;
+mod storage;
 mod ui;
+mod unlock;
+mod world;
 
 use std::io::{self, Write};
 
 use rand::Rng;
 
-use character::{Character, Combat, Fighter, Mage, Rogue, Warrior};
-use enemy::Enemy;
-use inventory::Item;
-use narrative::{Location, Narrative, RandomEvent};
+use ally::{AllyKind, Party};
+use boss::{Boss, Strike};
+use character::{
+    Background, Character, Class, Cleric, Combat, DamageType, EffectKind, Fighter, Mage,
+    Necromancer, Ranger, Rogue, Stat, StatusEffect, Talent, Warrior, BACKGROUNDS,
+};
+use chronicle::Chronicle;
+use daily::{DailyChallenge, Modifier};
+use dialogue::{Choice, Npc, Outcome};
+use dungeon::{Floor, RoomKind};
+use enemy::{Enemy, EnemyKind};
+use event::{CombatAction, GameEvent, GoldSource};
+use hazard::Hazard;
+use inventory::{
+    total_bonus, AffixStat, Filter, Inventory, Item, ItemEffect, Recipe, SortKey, BAG_CAPACITY,
+    RECIPES,
+};
+use leaderboard::{DailyLeaderboard, Leaderboard, Ranking, RunMode, RunRecord};
+use map::{WorldMap, LAIR_INTERVAL};
+use narrative::{EventOutcome, Location, LocationAction, Narrative, RandomEvent};
+use profile::Profile;
+use quest::{QuestDef, QuestLog, Reward, QUESTS};
+use rng::Stream;
 use ui::{
-    print_banner, print_divider, print_enemy_stats, print_health_bar, print_resource_bar,
-    show_character_stats,
+    pad, print_banner, print_divider, print_enemy_stats, print_health_bar, print_resource_bar,
+    show_achievements, show_character_stats, show_daily_challenge, show_hall_of_fame,
+    show_quest_log, show_run_stats,
 };
+use unlock::{embers_earned, Perk, Unlock, UNLOCKS};
+use world::{WorldState, BOSSES_SLAIN, BOSSES_SPARED, MAREN_AT_PEACE, OMENS_SEEN};
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
 fn main() -> Result<()> {
-    run_game()
+    print_banner();
+
+    loop {
+        println!("\n┌────────────────────────────────────────┐");
+        println!("│  [1] 🔥 New Adventure                  │");
+        println!("│  [2] 🎲 Adventure from a Seed          │");
+        println!("│  [3] 🏰 Dungeon Delve                  │");
+        println!("│  [4] 📅 Daily Challenge                │");
+        println!("│  [5] 🏆 Hall of Fame                   │");
+        println!("│  [6] 🏅 Achievements                   │");
+        println!("│  [7] 🔥 Ember Forge                    │");
+        println!("│  [8] 🚪 Quit                           │");
+        println!("└────────────────────────────────────────┘");
+        print!("\n➤ Choose (1-8): ");
+        let _ = io::stdout().flush();
+
+        match get_user_choice(8) {
+            1 => run_game(rng::fresh_seed(), &read_profile(), None)?,
+            // Seeds are for sharing, so unlocks are set aside to make them play the same for everyone
+            2 => run_game(get_seed(), &Profile::default(), None)?,
+            3 => {
+                let (seed, profile) = choose_dungeon_seed();
+                run_dungeon(seed, &profile)?
+            }
+            4 => daily_challenge()?,
+            5 => hall_of_fame(),
+            6 => show_achievements(&read_profile()),
+            7 => unlock_menu(),
+            8 => return Ok(()),
+            _ => unreachable!(),
+        }
+    }
 }
 
-fn run_game() -> Result<()> {
-    print_banner();
+fn run_game(seed: u64, profile: &Profile, daily: Option<&DailyChallenge>) -> Result<()> {
+    rng::seed(seed);
     println!("{}", Narrative::opening_verse());
+    println!("\x1b[90m🎲 Seed: {}\x1b[0m", seed);
+    let active = |modifier| daily.is_some_and(|challenge| challenge.has(modifier));
+    if let Some(challenge) = daily {
+        println!("📅 Daily Challenge {}", challenge.date());
+        for modifier in challenge.modifiers() {
+            println!(
+                "   {} {} - {}",
+                modifier.icon(),
+                modifier.name(),
+                modifier.description()
+            );
+        }
+    }
 
     // Character selection
-    let mut player_character = select_character();
+    let (mut player_character, background) = select_character(profile);
     player_character.introduce();
 
-    // Give starting items
-    println!("\nYou receive a Health Potion and an Energy Potion!");
+    const HOURS_PER_ENCOUNTER: u32 = 3;
+    const NIGHT_UNDEAD_FURY: u32 = 5;
+    const NIGHTFALL_HOURS: u32 = 12;
+    const BLOODTHIRST: u32 = 4;
 
-    const STARTING_GOLD: u32 = 50;
-    const POTION_VALUE: u32 = 50;
-    const ENERGY_POTION_VALUE: u32 = 40;
-
-    let mut gold = STARTING_GOLD;
-    let mut inventory = vec![
-        Item::new(String::from("Health Potion"), POTION_VALUE),
-        Item::new(String::from("Energy Potion"), ENERGY_POTION_VALUE),
-    ];
+    let (mut gold, mut inventory) = starting_kit(background, profile);
+    if active(Modifier::Pauper) {
+        gold = 0;
+        println!("{} Your purse is empty.", Modifier::Pauper.icon());
+    }
 
     println!("\n=== Your Adventure Begins! ===\n");
 
     // Battle loop
     let mut battle_count = 0;
+    let mut world_map = WorldMap::new(Unlock::locations(profile));
+    let mut quest_log = QuestLog::new();
+    let mut world = WorldState::new();
+    let mut chronicle = Chronicle::new(&*player_character, seed);
+    let mut party = Party::new();
+    if active(Modifier::Nightfall) {
+        world.clock_mut().advance(NIGHTFALL_HOURS);
+    }
     loop {
         battle_count += 1;
+        world.clock_mut().advance(HOURS_PER_ENCOUNTER);
+        track_event(&mut chronicle, &GameEvent::EncounterStarted(battle_count));
+
+        // Pick the road ahead
+        let location = choose_route(&mut world_map, battle_count, &party);
+        track_event(&mut chronicle, &GameEvent::LocationVisited(location));
+
+        // Random travel event - nothing distracts from the road to the lair
+        let mut events = rng::stream(seed, battle_count, Stream::Events);
+        let mut ambush = None;
+        if !battle_count.is_multiple_of(LAIR_INTERVAL) {
+            if let Some(event) = RandomEvent::try_trigger(&location, &mut events) {
+                world.increment(OMENS_SEEN);
+                ambush = resolve_random_event(
+                    event,
+                    &mut player_character,
+                    &mut gold,
+                    &mut inventory,
+                    &mut quest_log,
+                    &mut chronicle,
+                    &mut events,
+                );
+                std::thread::sleep(std::time::Duration::from_millis(800));
+            }
+        }
 
-        // Random travel event
-        if let Some(event) = RandomEvent::try_trigger() {
-            println!("{}", event);
-            std::thread::sleep(std::time::Duration::from_millis(800));
+        // Someone on the road may have work for a hero
+        if let Some(offer) = quest_log.roll_offer(&mut events) {
+            offer_quest(&mut quest_log, offer);
         }
 
         // Travel verse
-        println!("{}", Narrative::random_travel_verse());
+        println!(
+            "{}",
+            Narrative::random_travel_verse(world.clock().time_of_day())
+        );
         std::thread::sleep(std::time::Duration::from_millis(600));
 
         print_divider();
         println!("╔════════════════════════════════════════╗");
-        println!("║       ENCOUNTER #{:<2}                   ║", battle_count);
+        // The icon is kept out of the padded fields since it is two columns wide
+        let time = world.clock().time_of_day();
+        println!(
+            "║  ENCOUNTER #{:<2}  {:<13} {} {:<6}║",
+            battle_count,
+            world.clock().stamp(),
+            time.icon(),
+            time.name()
+        );
         println!("╚════════════════════════════════════════╝\n");
 
         // Location-based encounter
-        let location = Location::random(battle_count);
         println!("{}\n", location.description());
 
-        // Show location actions
-        let actions = location.available_actions();
+        // Show location actions; companions are only offered while the party has room for them
+        let actions: Vec<_> = location
+            .available_actions()
+            .into_iter()
+            .filter(|action| {
+                action
+                    .companion()
+                    .is_none_or(|kind| party.has_room_for(kind))
+            })
+            .collect();
+        let npc = Npc::at(&location);
         if !actions.is_empty() {
             println!("┌────────────────────────────────────────┐");
             println!("│  What would you like to do?            │");
@@ -79,6 +213,13 @@ fn run_game() -> Result<()> {
             for (i, action) in actions.iter().enumerate() {
                 println!("│  [{}] {:<35}│", i + 1, action.name());
             }
+            if let Some(npc) = npc {
+                println!(
+                    "│  [{}] 💬 {:<32}│",
+                    actions.len() + 1,
+                    format!("Speak with {}", npc.name)
+                );
+            }
             println!("│  [0] Press onward                      │");
             println!("└────────────────────────────────────────┘");
             print!("\n➤ Choose: ");
@@ -90,31 +231,128 @@ fn run_game() -> Result<()> {
                 if let Ok(choice) = input.trim().parse::<u32>() {
                     if choice > 0 && choice <= max_choice {
                         let action = &actions[(choice - 1) as usize];
-                        let result = action.execute(&mut player_character, &mut gold);
+                        let gold_before = gold;
+                        let result =
+                            action.execute(&location, &mut player_character, &mut gold, &mut world);
+                        world.clock_mut().advance(action.hours());
                         println!("\n{}\n", result);
+                        if let Some(kind) = action.companion() {
+                            if party.recruit(kind, player_character.get_level()) {
+                                println!("{} The {} joins your party!\n", kind.icon(), kind.name());
+                            }
+                        }
+                        if gold > gold_before {
+                            track_event(
+                                &mut chronicle,
+                                &GameEvent::GoldGained {
+                                    amount: gold - gold_before,
+                                    source: GoldSource::Found,
+                                },
+                            );
+                        }
                         std::thread::sleep(std::time::Duration::from_millis(800));
+                        record_event(
+                            &mut quest_log,
+                            &mut chronicle,
+                            GameEvent::ActionTaken(location, *action),
+                            &mut player_character,
+                            &mut gold,
+                            &mut inventory,
+                        );
+                    } else if let Some(npc) = npc.filter(|_| choice == max_choice + 1) {
+                        ambush = talk_to(
+                            npc,
+                            &mut player_character,
+                            &mut gold,
+                            &mut inventory,
+                            &mut quest_log,
+                            &mut world,
+                            &mut chronicle,
+                        );
                     }
                 }
             }
         }
 
         // Boss warning
-        if battle_count % 5 == 0 {
-            println!("{}", Narrative::boss_approach_verse());
+        if battle_count.is_multiple_of(LAIR_INTERVAL) {
+            println!("{}", Narrative::boss_approach_verse(&world));
             std::thread::sleep(std::time::Duration::from_millis(1000));
         }
 
         println!("\n{}", location.encounter_intro());
-        let mut enemy = spawn_enemy(battle_count);
-        println!(
-            "\n⚠️  A wild {} (Level {}) appears!",
-            enemy.get_name(),
-            enemy.get_level()
-        );
+        let mut boss = None;
+        let mut enemy = if battle_count.is_multiple_of(LAIR_INTERVAL) {
+            let (lair_boss, mut enemy) = Boss::for_lair(battle_count / LAIR_INTERVAL);
+            println!(
+                "\n\x1b[95m👑 BOSS: {} (Level {}) rises before you!\x1b[0m",
+                lair_boss.title(),
+                enemy.get_level()
+            );
+            // Wyrms remember the kin the player has slain
+            let kin_slain = world.count(BOSSES_SLAIN);
+            if kin_slain > 0 {
+                enemy.enrage(kin_slain * 3);
+                println!(
+                    "\x1b[91m💢 It seeks vengeance for its slain kin! (+{} attack)\x1b[0m",
+                    kin_slain * 3
+                );
+            }
+            boss = Some(lair_boss);
+            enemy
+        } else if let Some(kind) = ambush {
+            let enemy = Enemy::new(kind, enemy_level(battle_count, &location));
+            println!(
+                "\n⚔️  {} (Level {}) attacks!",
+                enemy.get_name(),
+                enemy.get_level()
+            );
+            enemy
+        } else {
+            let mut spawns = rng::stream(seed, battle_count, Stream::Spawns);
+            let enemy = spawn_enemy(battle_count, &location, &world, &mut spawns);
+            println!(
+                "\n⚠️  A wild {} (Level {}) appears!",
+                enemy.get_name(),
+                enemy.get_level()
+            );
+            enemy
+        };
+        // The dead grow bolder in the crypt after dark
+        if boss.is_none()
+            && location == Location::HauntedCrypt
+            && world.clock().is_night()
+            && enemy.kind().is_undead()
+        {
+            enemy.enrage(NIGHT_UNDEAD_FURY);
+            println!(
+                "\x1b[91m🌙 The night feeds the {}! (+{} attack)\x1b[0m",
+                enemy.get_name(),
+                NIGHT_UNDEAD_FURY
+            );
+        }
+        if active(Modifier::Bloodthirsty) {
+            enemy.enrage(BLOODTHIRST);
+            println!(
+                "\x1b[91m{} The {} thirsts for blood! (+{} attack)\x1b[0m",
+                Modifier::Bloodthirsty.icon(),
+                enemy.get_name(),
+                BLOODTHIRST
+            );
+        }
         print_enemy_stats(&enemy);
+        player_character.set_time_of_day(world.clock().time_of_day());
 
         // Battle
-        let won = battle(&mut player_character, &mut enemy, &mut inventory);
+        let won = battle(
+            &mut player_character,
+            &mut enemy,
+            boss.as_mut(),
+            location,
+            &mut inventory,
+            &mut party,
+            &mut chronicle,
+        );
 
         if !won {
             println!("{}", Narrative::death_verse());
@@ -127,13 +365,42 @@ fn run_game() -> Result<()> {
                 battle_count - 1
             );
             println!("╚════════════════════════════════════════╝");
+            show_run_stats(chronicle.stats());
+            record_run(
+                &*player_character,
+                battle_count - 1,
+                gold,
+                format!("Slain by {}", enemy.get_name()),
+                seed,
+                RunMode::Adventure,
+                daily,
+            );
+            award_embers(battle_count - 1, world.count(BOSSES_SLAIN));
+            offer_stats_export(&chronicle);
             break;
         }
 
         // Victory rewards
         println!("{}", Narrative::victory_verse());
-        let reward_gold = 20 + (battle_count * 5);
+        track_event(&mut chronicle, &GameEvent::BattleWon);
+        player_character.tick_effects();
+        for ally in party.end_battle() {
+            println!("{} The {} fades away.", ally.kind().icon(), ally.get_name());
+        }
+        // Renown from slain wyrms makes the grateful more generous
+        let renown = world.count(BOSSES_SLAIN) * 10;
+        let mut reward_gold = 20 + (battle_count * 5) + location.danger() * 5 + renown;
+        if active(Modifier::Bounty) {
+            reward_gold *= 2;
+        }
         gold += reward_gold;
+        track_event(
+            &mut chronicle,
+            &GameEvent::GoldGained {
+                amount: reward_gold,
+                source: GoldSource::Earned,
+            },
+        );
         println!("\n╔════════════════════════════════════════╗");
         println!("║            VICTORY!                    ║");
         println!("╠════════════════════════════════════════╣");
@@ -141,33 +408,62 @@ fn run_game() -> Result<()> {
         println!("║  Total Gold: {} coins                 ║", gold);
         println!("╚════════════════════════════════════════╝");
 
-        // Random loot with better chance after boss battles
-        let mut rng = rand::rng();
-        let loot_chance = if battle_count % 5 == 0 {
-            100 // Always drop loot after boss
-        } else {
-            rng.random_range(1..=100)
-        };
+        record_event(
+            &mut quest_log,
+            &mut chronicle,
+            GameEvent::EnemySlain(enemy.kind()),
+            &mut player_character,
+            &mut gold,
+            &mut inventory,
+        );
 
-        if loot_chance > 40 {
-            let item = Item::random_loot();
-            let rarity = if item.value() >= 500 {
-                "\x1b[95m★★★ LEGENDARY ★★★\x1b[0m"
-            } else if item.value() >= 150 {
-                "\x1b[93m★★ RARE ★★\x1b[0m"
-            } else if item.value() >= 60 {
-                "\x1b[92m★ UNCOMMON ★\x1b[0m"
+        // Bosses always drop their trophies; other foes roll for random loot
+        let mut loot = rng::stream(seed, battle_count, Stream::Loot);
+        if let Some(boss) = &boss {
+            let spared = decide_boss_fate(boss, &mut player_character, &mut world);
+            let trophies = if spared {
+                &boss.loot()[..1]
             } else {
-                "Common"
+                boss.loot()
             };
-
+            for &(name, value) in trophies {
+                println!(
+                    "\n🎁 \x1b[95m👑 BOSS TROPHY\x1b[0m You claim a \x1b[96m{}\x1b[0m! (Value: {}g)",
+                    name, value
+                );
+                if stash(&mut inventory, Item::new(name.to_string(), value)) {
+                    record_event(
+                        &mut quest_log,
+                        &mut chronicle,
+                        GameEvent::ItemFound(name.to_string()),
+                        &mut player_character,
+                        &mut gold,
+                        &mut inventory,
+                    );
+                }
+            }
+        } else if loot.random_range(1..=100) > 40 {
+            let item = Item::random_loot(battle_count, &mut loot);
             println!(
                 "\n🎁 {} You found a \x1b[96m{}\x1b[0m! (Value: {}g)",
-                rarity,
-                item.name(),
+                item.rarity().banner(),
+                item.display_name(),
                 item.value()
             );
-            inventory.push(item);
+            for affix in item.affixes() {
+                println!("   ✦ {}: {}", affix.name, affix.stat.describe(affix.bonus));
+            }
+            let found = GameEvent::ItemFound(item.name().to_string());
+            if stash(&mut inventory, item) {
+                record_event(
+                    &mut quest_log,
+                    &mut chronicle,
+                    found,
+                    &mut player_character,
+                    &mut gold,
+                    &mut inventory,
+                );
+            }
         } else {
             println!("\n💨 The enemy had no loot to speak of.");
         }
@@ -181,20 +477,38 @@ fn run_game() -> Result<()> {
             println!("│  [1] ⚔️  Continue Quest                │");
             println!("│  [2] 📊 Stats                          │");
             println!("│  [3] 🎒 Inventory                      │");
-            println!("│  [4] 🏠 Rest & Quit                    │");
+            println!("│  [4] ⚒️  Crafting                       │");
+            println!("│  [5] 📜 Quest Log                      │");
+            println!(
+                "│  [6] 🌟 {:<31}│",
+                match player_character.talent_points() {
+                    0 => "Talents".to_string(),
+                    points => format!("Talents ({} point(s) to spend)", points),
+                }
+            );
+            println!("│  [7] 🏠 Rest & Quit                    │");
             println!("└────────────────────────────────────────┘");
-            print!("\n➤ Choose (1-4): ");
+            print!("\n➤ Choose (1-7): ");
             let _ = io::stdout().flush();
 
-            match get_user_choice(4) {
+            match get_user_choice(7) {
                 1 => break, // Continue to next encounter
                 2 => {
                     show_character_stats(&*player_character, gold, battle_count);
                 }
                 3 => {
-                    use_inventory_menu(&mut player_character, &mut inventory, gold);
+                    use_inventory_menu(&mut player_character, &mut inventory, gold, &mut chronicle);
                 }
                 4 => {
+                    crafting_menu(&mut inventory);
+                }
+                5 => {
+                    show_quest_log(&quest_log);
+                }
+                6 => {
+                    talent_menu(&mut player_character, &mut chronicle);
+                }
+                7 => {
                     print_divider();
                     println!("╔════════════════════════════════════════╗");
                     println!("║        ADVENTURE COMPLETE!             ║");
@@ -202,10 +516,22 @@ fn run_game() -> Result<()> {
                     show_character_stats(&*player_character, gold, battle_count);
                     println!(
                         "║  Items Collected: {:<2}                 ║",
-                        inventory.len()
+                        inventory.item_count()
                     );
                     println!("╚════════════════════════════════════════╝");
                     println!("\n🏰 You rest at the tavern. Safe travels, hero!\n");
+                    show_run_stats(chronicle.stats());
+                    record_run(
+                        &*player_character,
+                        battle_count,
+                        gold,
+                        "Retired at the tavern".to_string(),
+                        seed,
+                        RunMode::Adventure,
+                        daily,
+                    );
+                    award_embers(battle_count, world.count(BOSSES_SLAIN));
+                    offer_stats_export(&chronicle);
                     return Ok(());
                 }
                 _ => unreachable!(),
@@ -215,29 +541,1110 @@ fn run_game() -> Result<()> {
     Ok(())
 }
 
-fn select_character() -> Box<dyn Fighter> {
+// Dungeon mode: clear floors of connected rooms, each deeper than the last
+fn run_dungeon(seed: u64, profile: &Profile) -> Result<()> {
+    const FLOOR_ENCOUNTERS: u32 = 3; // Each floor's foes match this many more encounters of a normal run
+    const FLOOR_EXPERIENCE: u32 = 100;
+
+    rng::seed(seed);
+    println!("{}", Narrative::dungeon_verse());
+    println!("\x1b[90m🎲 Seed: {}\x1b[0m", seed);
+
+    let (mut player, background) = select_character(profile);
+    player.introduce();
+    let (mut gold, mut inventory) = starting_kit(background, profile);
+
+    let mut world = WorldState::new();
+    let mut party = Party::new();
+    let mut chronicle = Chronicle::new(&*player, seed);
+    let mut floor = Floor::generate(seed, 1);
+    let mut battles_won = 0;
+
+    loop {
+        let depth = floor.depth();
+        let theme = floor.theme();
+        let encounter = (depth - 1) * FLOOR_ENCOUNTERS + 1;
+        print_divider();
+        println!("╔════════════════════════════════════════╗");
+        println!(
+            "║  🏰 {:<35}║",
+            format!("DUNGEON FLOOR {} - {}", depth, theme.name())
+        );
+        println!("╚════════════════════════════════════════╝\n");
+        for line in floor.render() {
+            println!("{}", line);
+        }
+        println!("\n\x1b[90m  @ you  ? unexplored  B boss  + shrine  . cleared\x1b[0m");
+
+        let exits = floor.exits();
+        println!("\n┌────────────────────────────────────────┐");
+        println!("│  {:<38}│", floor.current_room().kind.name());
+        println!("├────────────────────────────────────────┤");
+        for (i, direction) in exits.iter().enumerate() {
+            println!("│  [{}] 🚪 Go {:<28}│", i + 1, direction.name());
+        }
+        let menu = exits.len() as u32;
+        println!("│  [{}] 📊 Stats                          │", menu + 1);
+        println!("│  [{}] 🎒 Inventory                      │", menu + 2);
+        println!("│  [{}] 🌟 Talents                        │", menu + 3);
+        println!("│  [{}] 🏳️  Leave the dungeon              │", menu + 4);
+        println!("└────────────────────────────────────────┘");
+        print!("\n➤ Choose (1-{}): ", menu + 4);
+        let _ = io::stdout().flush();
+
+        let choice = get_user_choice(menu + 4);
+        if choice == menu + 1 {
+            show_character_stats(&*player, gold, battles_won);
+            continue;
+        } else if choice == menu + 2 {
+            use_inventory_menu(&mut player, &mut inventory, gold, &mut chronicle);
+            continue;
+        } else if choice == menu + 3 {
+            talent_menu(&mut player, &mut chronicle);
+            continue;
+        } else if choice == menu + 4 {
+            end_dungeon_run(
+                &*player,
+                &chronicle,
+                depth - 1,
+                battles_won,
+                gold,
+                format!("Left the dungeon from floor {}", depth),
+                seed,
+            );
+            return Ok(());
+        }
+
+        let room = floor.step(exits[(choice - 1) as usize]);
+        if room.is_cleared() {
+            continue;
+        }
+        let kind = room.kind;
+        println!("\n🚪 You enter the {}...", kind.name());
+
+        match kind {
+            RoomKind::Entrance => {}
+            RoomKind::Combat | RoomKind::Boss => {
+                let mut boss = None;
+                let mut enemy = if kind == RoomKind::Boss {
+                    let (den_boss, enemy) = Boss::for_lair(depth);
+                    println!(
+                        "\n\x1b[95m👑 BOSS: {} (Level {}) rises before you!\x1b[0m",
+                        den_boss.title(),
+                        enemy.get_level()
+                    );
+                    boss = Some(den_boss);
+                    enemy
+                } else {
+                    let enemy = spawn_enemy(encounter, &theme, &world, &mut rng::rng());
+                    println!(
+                        "\n⚠️  A {} (Level {}) guards this chamber!",
+                        enemy.get_name(),
+                        enemy.get_level()
+                    );
+                    enemy
+                };
+                print_enemy_stats(&enemy);
+                track_event(
+                    &mut chronicle,
+                    &GameEvent::EncounterStarted(battles_won + 1),
+                );
+
+                let won = battle(
+                    &mut player,
+                    &mut enemy,
+                    boss.as_mut(),
+                    theme,
+                    &mut inventory,
+                    &mut party,
+                    &mut chronicle,
+                );
+                if !won {
+                    println!("{}", Narrative::death_verse());
+                    end_dungeon_run(
+                        &*player,
+                        &chronicle,
+                        depth - 1,
+                        battles_won,
+                        gold,
+                        format!("Slain by {} on floor {}", enemy.get_name(), depth),
+                        seed,
+                    );
+                    return Ok(());
+                }
+
+                battles_won += 1;
+                track_event(&mut chronicle, &GameEvent::BattleWon);
+                track_event(&mut chronicle, &GameEvent::EnemySlain(enemy.kind()));
+                player.tick_effects();
+                for ally in party.end_battle() {
+                    println!("{} The {} fades away.", ally.kind().icon(), ally.get_name());
+                }
+                let reward_gold = 15 + depth * 10;
+                gold += reward_gold;
+                track_event(
+                    &mut chronicle,
+                    &GameEvent::GoldGained {
+                        amount: reward_gold,
+                        source: GoldSource::Earned,
+                    },
+                );
+                println!("\n💰 You collect {} gold. (Total: {})", reward_gold, gold);
+
+                if let Some(boss) = &boss {
+                    for &(name, value) in boss.loot() {
+                        println!(
+                            "\n🎁 \x1b[95m👑 BOSS TROPHY\x1b[0m You claim a \x1b[96m{}\x1b[0m! (Value: {}g)",
+                            name, value
+                        );
+                        if stash(&mut inventory, Item::new(name.to_string(), value)) {
+                            track_event(&mut chronicle, &GameEvent::ItemFound(name.to_string()));
+                        }
+                    }
+                    player.gain_experience(FLOOR_EXPERIENCE * depth);
+                }
+            }
+            RoomKind::Treasure => {
+                let found_gold = rng::rng().random_range(10..=30) + depth * 10;
+                gold += found_gold;
+                track_event(
+                    &mut chronicle,
+                    &GameEvent::GoldGained {
+                        amount: found_gold,
+                        source: GoldSource::Found,
+                    },
+                );
+                let item = Item::random_loot(encounter, &mut rng::rng());
+                println!(
+                    "\n🎁 {} A chest holds {} gold and a \x1b[96m{}\x1b[0m! (Value: {}g)",
+                    item.rarity().banner(),
+                    found_gold,
+                    item.display_name(),
+                    item.value()
+                );
+                let found = GameEvent::ItemFound(item.name().to_string());
+                if stash(&mut inventory, item) {
+                    track_event(&mut chronicle, &found);
+                }
+            }
+            RoomKind::Trap => {
+                let mut rng = rng::rng();
+                // Nimble heroes may leap clear
+                if rng.random_range(1..=100) <= player.stat(Stat::Agility) * 2 {
+                    println!("\n\x1b[96m💨 A blade swings from the wall, but you dive beneath it!\x1b[0m");
+                } else {
+                    let damage = rng.random_range(6..=12) + depth * 4;
+                    println!("\n\x1b[31m⚠ The floor gives way to a pit of spikes!\x1b[0m");
+                    player.take_damage(damage);
+                    track_event(
+                        &mut chronicle,
+                        &GameEvent::DamageTaken {
+                            amount: damage,
+                            critical: false,
+                        },
+                    );
+                    if !player.is_alive() {
+                        println!("{}", Narrative::death_verse());
+                        end_dungeon_run(
+                            &*player,
+                            &chronicle,
+                            depth - 1,
+                            battles_won,
+                            gold,
+                            format!("Killed by a trap on floor {}", depth),
+                            seed,
+                        );
+                        return Ok(());
+                    }
+                }
+            }
+            RoomKind::Shrine => {
+                let result =
+                    LocationAction::Pray.execute(&theme, &mut player, &mut gold, &mut world);
+                println!("\n{}", result);
+            }
+        }
+        floor.clear_current();
+
+        // A slain floor boss opens the stairs down
+        if kind == RoomKind::Boss {
+            println!("\n┌────────────────────────────────────────┐");
+            println!("│  Stairs lead deeper into the dark...   │");
+            println!("├────────────────────────────────────────┤");
+            println!("│  [1] ⬇️  Descend to floor {:<13}│", depth + 1);
+            println!("│  [2] 🏳️  Leave the dungeon              │");
+            println!("└────────────────────────────────────────┘");
+            print!("\n➤ Choose (1-2): ");
+            let _ = io::stdout().flush();
+
+            if get_user_choice(2) == 2 {
+                end_dungeon_run(
+                    &*player,
+                    &chronicle,
+                    depth,
+                    battles_won,
+                    gold,
+                    format!("Escaped after clearing floor {}", depth),
+                    seed,
+                );
+                return Ok(());
+            }
+            floor = Floor::generate(seed, depth + 1);
+            println!("\n\x1b[90mThe air grows colder as you descend...\x1b[0m");
+        }
+    }
+}
+
+fn end_dungeon_run(
+    player: &dyn Fighter,
+    chronicle: &Chronicle,
+    floors_cleared: u32,
+    battles_won: u32,
+    gold: u32,
+    cause: String,
+    seed: u64,
+) {
+    print_divider();
+    println!("╔════════════════════════════════════════╗");
+    println!("║          DELVE OVER                    ║");
+    println!("╠════════════════════════════════════════╣");
+    println!("║  Floors cleared: {:<22}║", floors_cleared);
+    println!("║  Battles won: {:<25}║", battles_won);
+    println!("╚════════════════════════════════════════╝");
+    println!("\n{}", cause);
+    show_run_stats(chronicle.stats());
+    record_run(player, battles_won, gold, cause, seed, RunMode::Delve, None);
+    award_embers(battles_won, floors_cleared);
+    offer_stats_export(chronicle);
+}
+
+// Gold and items every hero sets out with, plus what their background and unlocks grant
+fn starting_kit(background: &Background, profile: &Profile) -> (u32, Inventory) {
+    println!("\nYou receive a Health Potion and an Energy Potion!");
+
+    const STARTING_GOLD: u32 = 50;
+    const POTION_VALUE: u32 = 50;
+    const ENERGY_POTION_VALUE: u32 = 40;
+
+    let mut gold = STARTING_GOLD;
+    let mut inventory = Inventory::new();
+    stash(
+        &mut inventory,
+        Item::new(String::from("Health Potion"), POTION_VALUE),
+    );
+    stash(
+        &mut inventory,
+        Item::new(String::from("Energy Potion"), ENERGY_POTION_VALUE),
+    );
+
+    // What the hero brings from their old life
+    println!(
+        "Your {} past grants: {}",
+        background.name.to_lowercase(),
+        background.describe()
+    );
+    gold += background.gold;
+    for &(name, value) in background.items {
+        stash(&mut inventory, Item::new(name.to_string(), value));
+    }
+
+    // Boons bought with embers in earlier runs
+    let boons: Vec<&str> = Unlock::owned(profile)
+        .filter_map(|unlock| match unlock.perk {
+            Perk::StartingItem(name, value) => {
+                stash(&mut inventory, Item::new(name.to_string(), value));
+                Some(unlock.name)
+            }
+            Perk::StartingGold(amount) => {
+                gold += amount;
+                Some(unlock.name)
+            }
+            _ => None,
+        })
+        .collect();
+    if !boons.is_empty() {
+        println!("🔥 Your unlocks provide: {}", boons.join(", "));
+    }
+    (gold, inventory)
+}
+
+// Runs a conversation; returns the enemy to fight if the NPC turns hostile
+fn talk_to(
+    npc: &Npc,
+    player: &mut Box<dyn Fighter>,
+    gold: &mut u32,
+    inventory: &mut Inventory,
+    quest_log: &mut QuestLog,
+    world: &mut WorldState,
+    chronicle: &mut Chronicle,
+) -> Option<EnemyKind> {
+    let mut node = 0;
+    loop {
+        let current = &npc.nodes[node];
+        let choices: Vec<&Choice> = current
+            .choices
+            .iter()
+            .filter(|choice| {
+                choice
+                    .conditions
+                    .iter()
+                    .all(|condition| condition.is_met(&**player, *gold, inventory, world))
+            })
+            .collect();
+
+        println!("\n\x1b[96m💬 {}\x1b[0m", npc.name);
+        println!("\x1b[37m{}\x1b[0m", current.line);
+        println!("┌────────────────────────────────────────┐");
+        for (i, choice) in choices.iter().enumerate() {
+            let tags: String = choice
+                .conditions
+                .iter()
+                .filter_map(|condition| condition.label())
+                .collect();
+            println!("│  [{}] {}{}", i + 1, tags, choice.text);
+        }
+        println!("└────────────────────────────────────────┘");
+        print!("\n➤ Choose (1-{}): ", choices.len());
+        let _ = io::stdout().flush();
+
+        let choice = choices[(get_user_choice(choices.len() as u32) - 1) as usize];
+        if let Some(flag) = choice.remember {
+            world.set_flag(flag);
+        }
+
+        for outcome in choice.outcomes {
+            match *outcome {
+                Outcome::Lore(text) => println!("\n\x1b[37m{}\x1b[0m", text),
+                Outcome::Buy { item, value, cost } => {
+                    if stash(inventory, Item::new(item.to_string(), value)) {
+                        *gold -= cost;
+                        println!("\n💰 You buy a {} for {} gold.", item, cost);
+                    }
+                }
+                Outcome::Sell { item, price } => {
+                    if inventory.take_named(item).is_some() {
+                        *gold += price;
+                        println!("\n💰 You sell a {} for {} gold.", item, price);
+                        track_event(
+                            chronicle,
+                            &GameEvent::GoldGained {
+                                amount: price,
+                                source: GoldSource::Earned,
+                            },
+                        );
+                    }
+                }
+                Outcome::Gift { item, value } => {
+                    if stash(inventory, Item::new(item.to_string(), value)) {
+                        println!("\n🎁 You receive a {}!", item);
+                    }
+                }
+                Outcome::Quest(title) => {
+                    if let Some(def) = QUESTS.iter().find(|def| def.title == title) {
+                        if quest_log.knows(def) {
+                            println!("\n📜 You have already taken on '{}'.", title);
+                        } else if quest_log.accept(def) {
+                            println!("\n📜 Quest accepted: {}", title);
+                        } else {
+                            println!(
+                                "\n📜 Your quest log is full. '{}' will have to wait.",
+                                title
+                            );
+                        }
+                    }
+                }
+                Outcome::Fight(kind) => return Some(kind),
+            }
+        }
+        std::thread::sleep(std::time::Duration::from_millis(600));
+
+        match choice.next {
+            Some(next) => node = next,
+            None => return None,
+        }
+    }
+}
+
+// Presents a travel event's choices and applies the rolled outcome;
+// returns the enemy to fight if the event ends in an ambush
+fn resolve_random_event(
+    event: &RandomEvent,
+    player: &mut Box<dyn Fighter>,
+    gold: &mut u32,
+    inventory: &mut Inventory,
+    quest_log: &mut QuestLog,
+    chronicle: &mut Chronicle,
+    rng: &mut impl Rng,
+) -> Option<EnemyKind> {
+    println!("\n{}", event.text);
+    println!("┌────────────────────────────────────────┐");
+    for (i, choice) in event.choices.iter().enumerate() {
+        println!("│  [{}] {:<35}│", i + 1, choice.text);
+    }
+    println!("└────────────────────────────────────────┘");
+    print!("\n➤ Choose (1-{}): ", event.choices.len());
+    let _ = io::stdout().flush();
+
+    let choice = &event.choices[(get_user_choice(event.choices.len() as u32) - 1) as usize];
+    let result = choice.roll(rng);
+    println!("\n{}", result.text);
+
+    let mut ambush = None;
+    for outcome in result.outcomes {
+        match *outcome {
+            EventOutcome::Gold(amount) => {
+                *gold += amount;
+                println!("💰 +{} gold", amount);
+                track_event(
+                    chronicle,
+                    &GameEvent::GoldGained {
+                        amount,
+                        source: GoldSource::Found,
+                    },
+                );
+            }
+            EventOutcome::Item(name, value) => {
+                if stash(inventory, Item::new(name.to_string(), value)) {
+                    println!("🎁 You obtain a {}!", name);
+                    record_event(
+                        quest_log,
+                        chronicle,
+                        GameEvent::ItemFound(name.to_string()),
+                        player,
+                        gold,
+                        inventory,
+                    );
+                }
+            }
+            EventOutcome::Heal(amount) => player.heal(amount),
+            EventOutcome::Damage(amount) => {
+                // Hazards on the road wound, but never kill
+                let damage = amount.min(player.get_health().saturating_sub(1));
+                player.take_damage(damage);
+            }
+            EventOutcome::Effect(kind, battles) => {
+                player.apply_effect(StatusEffect::new(kind, battles));
+            }
+            EventOutcome::Ambush(kind) => ambush = Some(kind),
+        }
+    }
+    ambush
+}
+
+fn offer_quest(quest_log: &mut QuestLog, offer: &'static QuestDef) {
+    let rewards: Vec<String> = offer.rewards.iter().map(Reward::describe).collect();
+
+    println!("\n\x1b[33m📜 {}\x1b[0m", offer.giver);
+    println!("┌────────────────────────────────────────┐");
+    println!("│  QUEST: {:<31}│", offer.title);
+    println!("├────────────────────────────────────────┤");
+    println!("│  Objective: {:<27}│", offer.description);
+    println!("│  Reward: {}", rewards.join(", "));
+    println!("├────────────────────────────────────────┤");
+    println!("│  [1] Accept                            │");
+    println!("│  [2] Decline                           │");
+    println!("└────────────────────────────────────────┘");
+    print!("\n➤ Choose (1-2): ");
+    let _ = io::stdout().flush();
+
+    if get_user_choice(2) == 1 {
+        if quest_log.accept(offer) {
+            println!("\n📜 Quest accepted: {}", offer.title);
+        }
+    } else {
+        println!("\n💨 You leave the task to someone else.");
+    }
+}
+
+// Feeds a game event to the chronicle and the quest log, and pays out any quests it completes
+fn record_event(
+    quest_log: &mut QuestLog,
+    chronicle: &mut Chronicle,
+    event: GameEvent,
+    player: &mut Box<dyn Fighter>,
+    gold: &mut u32,
+    inventory: &mut Inventory,
+) {
+    track_event(chronicle, &event);
+    for quest in quest_log.record(&event) {
+        println!("\n\x1b[93m📜 QUEST COMPLETE: {}!\x1b[0m", quest.title);
+        for reward in quest.rewards {
+            match *reward {
+                Reward::Gold(amount) => {
+                    *gold += amount;
+                    println!("💰 +{} gold", amount);
+                    track_event(
+                        chronicle,
+                        &GameEvent::GoldGained {
+                            amount,
+                            source: GoldSource::Earned,
+                        },
+                    );
+                }
+                Reward::Item(name, value) => {
+                    if stash(inventory, Item::new(name.to_string(), value)) {
+                        println!("🎁 Received {}", name);
+                    }
+                }
+                Reward::Experience(amount) => player.gain_experience(amount),
+            }
+        }
+        std::thread::sleep(std::time::Duration::from_millis(800));
+    }
+}
+
+// Records an event for the run's stats, announcing and saving any achievements it unlocks
+fn track_event(chronicle: &mut Chronicle, event: &GameEvent) {
+    let unlocked = chronicle.record(event);
+    for achievement in &unlocked {
+        println!(
+            "\n\x1b[93m🏅 ACHIEVEMENT UNLOCKED: {}\x1b[0m",
+            achievement.title
+        );
+        println!("   \x1b[90m{}\x1b[0m", achievement.description);
+    }
+    if !unlocked.is_empty() {
+        if let Err(err) = chronicle.save_achievements() {
+            eprintln!("⚠️  Could not save achievements: {}", err);
+        }
+    }
+}
+
+fn choose_route(world_map: &mut WorldMap, encounter: u32, party: &Party) -> Location {
+    let routes = world_map.routes(encounter);
+    let until_lair = WorldMap::encounters_until_lair(encounter);
+    let traveled = LAIR_INTERVAL - until_lair;
+
+    print_divider();
+    println!("┌────────────────────────────────────────┐");
+    println!("│  🗺️  CHOOSE YOUR ROAD                   │");
+    println!("├────────────────────────────────────────┤");
+    if let Some(current) = world_map.current() {
+        println!("│  From: {:<32}│", current.name());
+    }
+    println!(
+        "│  🐉 Lair: {}{} {:<22}│",
+        "■".repeat(traveled as usize),
+        "□".repeat(until_lair as usize),
+        if until_lair == 0 {
+            "You have arrived".to_string()
+        } else {
+            format!("{} encounter(s) away", until_lair)
+        }
+    );
+    println!("├────────────────────────────────────────┤");
+
+    // All roads converge on the lair
+    if routes.len() == 1 {
+        println!("│  ➤ {:<36}│", routes[0].name());
+        println!("└────────────────────────────────────────┘");
+        println!(
+            "\n\x1b[91mEvery path now leads to {}...\x1b[0m",
+            routes[0].name()
+        );
+        world_map.travel(routes[0]);
+        return routes[0];
+    }
+
+    for (i, route) in routes.iter().enumerate() {
+        println!(
+            "│  [{}] {:<18} Danger: {:<5}  │",
+            i + 1,
+            route.name(),
+            "☠".repeat(route.danger() as usize)
+        );
+        let hints = route.route_hints(|kind| party.has_room_for(kind));
+        if !hints.is_empty() {
+            const HINT_WIDTH: usize = 34;
+            let mut line = String::new();
+            for hint in hints {
+                if !line.is_empty() && line.chars().count() + hint.chars().count() + 2 > HINT_WIDTH
+                {
+                    println!("│      {}│", pad(&line, HINT_WIDTH));
+                    line.clear();
+                }
+                if !line.is_empty() {
+                    line.push_str(", ");
+                }
+                line.push_str(hint);
+            }
+            println!("│      {}│", pad(&line, HINT_WIDTH));
+        }
+    }
+    println!("└────────────────────────────────────────┘");
+    print!("\n➤ Choose your destination (1-{}): ", routes.len());
+    let _ = io::stdout().flush();
+
+    let destination = routes[(get_user_choice(routes.len() as u32) - 1) as usize];
+    world_map.travel(destination);
+    destination
+}
+
+// Character creation: class, name, background, then training points
+fn select_character(profile: &Profile) -> (Box<dyn Fighter>, &'static Background) {
     println!("┌────────────────────────────────────────┐");
     println!("│  SELECT YOUR CLASS                     │");
     println!("├────────────────────────────────────────┤");
     println!("│  [1] ⚔️  WARRIOR - Tank & Heavy Hitter │");
     println!("│  [2] 🔮 MAGE    - Spell Caster         │");
     println!("│  [3] 🗡️  ROGUE   - Swift Assassin      │");
+    println!("│  [4] ✨ CLERIC  - Holy Smiter          │");
+    println!("│  [5] 🏹 RANGER  - Archer of the Wilds  │");
+    println!("│  [6] 💀 NECROMANCER - Reaper of Souls  │");
+    println!("└────────────────────────────────────────┘");
+    print!("\n➤ Choose (1-6): ");
+    let _ = io::stdout().flush();
+
+    let class = Class::ALL[(get_user_choice(6) - 1) as usize];
+    let variant = select_variant(class, profile);
+    let name = get_player_name();
+    let background = select_background();
+    (
+        train_character(class, &name, background, variant),
+        background,
+    )
+}
+
+// Offers any unlocked variants of the class; returns the stat training they add
+fn select_variant(class: Class, profile: &Profile) -> Option<(Stat, u32)> {
+    let variants = Unlock::variants(profile, class);
+    if variants.is_empty() {
+        return None;
+    }
+
+    println!("\n┌────────────────────────────────────────┐");
+    println!("│  CHOOSE YOUR PATH                      │");
+    println!("├────────────────────────────────────────┤");
+    println!("│  [1] {:<34}│", format!("Standard {}", class.name()));
+    for (i, variant) in variants.iter().enumerate() {
+        println!("│  [{}] {:<34}│", i + 2, variant.name);
+        println!("│      \x1b[90m{:<34}\x1b[0m│", variant.description);
+    }
+    println!("└────────────────────────────────────────┘");
+    print!("\n➤ Choose (1-{}): ", variants.len() + 1);
+    let _ = io::stdout().flush();
+
+    let choice = get_user_choice(variants.len() as u32 + 1) as usize;
+    match variants
+        .get(choice.wrapping_sub(2))
+        .map(|variant| variant.perk)
+    {
+        Some(Perk::Variant { stat, points, .. }) => Some((stat, points)),
+        _ => None,
+    }
+}
+
+fn new_fighter(class: Class, name: &str) -> Box<dyn Fighter> {
+    let name = name.to_string();
+    match class {
+        Class::Warrior => Box::new(Warrior::new(name, 1)),
+        Class::Mage => Box::new(Mage::new(name, 1)),
+        Class::Rogue => Box::new(Rogue::new(name, 1)),
+        Class::Cleric => Box::new(Cleric::new(name, 1)),
+        Class::Ranger => Box::new(Ranger::new(name, 1)),
+        Class::Necromancer => Box::new(Necromancer::new(name, 1)),
+    }
+}
+
+fn select_background() -> &'static Background {
+    println!("\n┌────────────────────────────────────────┐");
+    println!("│  CHOOSE YOUR BACKGROUND                │");
+    println!("├────────────────────────────────────────┤");
+    for (i, background) in BACKGROUNDS.iter().enumerate() {
+        println!("│  [{}] {:<34}│", i + 1, background.name);
+        println!("│      {:<34}│", background.description);
+        println!("│      \x1b[90m{:<34}\x1b[0m│", background.describe());
+    }
     println!("└────────────────────────────────────────┘");
-    print!("\n➤ Choose (1-3): ");
+    print!("\n➤ Choose (1-{}): ", BACKGROUNDS.len());
     let _ = io::stdout().flush();
 
-    match get_user_choice(3) {
-        1 => {
-            let name = get_player_name();
-            Box::new(Warrior::new(name, 1))
+    &BACKGROUNDS[(get_user_choice(BACKGROUNDS.len() as u32) - 1) as usize]
+}
+
+// Spends the starting stat points, previewing what each one would change
+fn train_character(
+    class: Class,
+    name: &str,
+    background: &Background,
+    variant: Option<(Stat, u32)>,
+) -> Box<dyn Fighter> {
+    const STAT_POINTS: u32 = 5;
+
+    let stats = class.stats();
+    let build = |spent: &[u32]| {
+        let mut hero = new_fighter(class, name);
+        for (stat, points) in background.bonus.into_iter().chain(variant) {
+            hero.raise_stat(stat, points);
+        }
+        for (&stat, &points) in stats.iter().zip(spent) {
+            hero.raise_stat(stat, points);
+        }
+        hero
+    };
+    let mut spent = vec![0; stats.len()];
+
+    loop {
+        let hero = build(&spent);
+        let untrained = build(&vec![0; stats.len()]);
+        let remaining = STAT_POINTS - spent.iter().sum::<u32>();
+        let special = hero.special_ability_name().to_string();
+
+        println!("\n┌────────────────────────────────────────┐");
+        println!("│  TRAIN YOUR {:<27}│", class.name().to_uppercase());
+        println!("│  Points left: {:<25}│", remaining);
+        println!("├────────────────────────────────────────┤");
+        println!(
+            "│  ⚔️  Attack: {:<26}│",
+            preview(untrained.attack(), hero.attack())
+        );
+        println!(
+            "│  ✨ {}: {:<w$}│",
+            special,
+            preview(untrained.special_damage(), hero.special_damage()),
+            w = 33 - special.chars().count()
+        );
+        println!(
+            "│  ❤️  Max HP: {:<26}│",
+            preview(untrained.get_max_health(), hero.get_max_health())
+        );
+        println!("├────────────────────────────────────────┤");
+        for (i, &stat) in stats.iter().enumerate() {
+            let mut more = spent.clone();
+            more[i] += 1;
+            let gains = stat_gains(&*hero, &*build(&more));
+            println!(
+                "│  [{}] {:<12} {:>4}{:17}│",
+                i + 1,
+                stat.name(),
+                hero.stat(stat),
+                ""
+            );
+            println!("│      {}│", pad(&gains, 34));
         }
-        2 => {
-            let name = get_player_name();
-            Box::new(Mage::new(name, 1))
+        println!("│  [R] Reset points                      │");
+        println!("│  [0] Done                              │");
+        println!("└────────────────────────────────────────┘");
+        print!("\n➤ Choose a stat to train: ");
+        let _ = io::stdout().flush();
+
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input).is_err() {
+            eprintln!("Failed to read input");
+            continue;
         }
-        _ => {
-            let name = get_player_name();
-            Box::new(Rogue::new(name, 1))
+        match input.trim().to_lowercase().as_str() {
+            "r" => spent.iter_mut().for_each(|points| *points = 0),
+            "0" if remaining > 0 => {
+                println!("You still have {} point(s) to spend.", remaining);
+            }
+            "0" => return hero,
+            choice => match choice.parse::<usize>() {
+                Ok(num) if (1..=stats.len()).contains(&num) && remaining > 0 => {
+                    spent[num - 1] += 1;
+                }
+                Ok(num) if (1..=stats.len()).contains(&num) => {
+                    println!("No points left. Reset or press 0 to finish.");
+                }
+                _ => println!("Invalid choice. Please try again."),
+            },
+        }
+    }
+}
+
+fn preview(before: u32, after: u32) -> String {
+    if after > before {
+        format!("{} → {} (+{})", before, after, after - before)
+    } else {
+        before.to_string()
+    }
+}
+
+// What one more point would change, e.g. "+1 Atk, +2 Shield Bash"
+fn stat_gains(hero: &dyn Fighter, trained: &dyn Fighter) -> String {
+    let mut gains = Vec::new();
+    let attack = trained.attack() - hero.attack();
+    if attack > 0 {
+        gains.push(format!("+{} Atk", attack));
+    }
+    let special = trained.special_damage() - hero.special_damage();
+    if special > 0 {
+        gains.push(format!("+{} {}", special, hero.special_ability_name()));
+    }
+    let health = trained.get_max_health() - hero.get_max_health();
+    if health > 0 {
+        gains.push(format!("+{} HP", health));
+    }
+    let soaked = hero.damage_taken_modifier() - trained.damage_taken_modifier();
+    if soaked > 0.0 {
+        gains.push(format!("-{:.0}% damage taken", soaked * 100.0));
+    }
+    format!("\x1b[90m{}\x1b[0m", gains.join(", "))
+}
+
+// A dungeon entered from a seed sets unlocks aside, like a seeded adventure
+fn choose_dungeon_seed() -> (u64, Profile) {
+    println!("\n┌────────────────────────────────────────┐");
+    println!("│  [1] 🎲 Random dungeon                 │");
+    println!("│  [2] 🔢 Dungeon from a seed            │");
+    println!("└────────────────────────────────────────┘");
+    print!("\n➤ Choose (1-2): ");
+    let _ = io::stdout().flush();
+
+    match get_user_choice(2) {
+        1 => (rng::fresh_seed(), read_profile()),
+        _ => (get_seed(), Profile::default()),
+    }
+}
+
+fn get_seed() -> u64 {
+    loop {
+        print!("Enter a seed: ");
+        let _ = io::stdout().flush();
+
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input).is_err() {
+            eprintln!("Failed to read input");
+            continue;
+        }
+        match input.trim().parse::<u64>() {
+            Ok(seed) => return seed,
+            Err(_) => println!("A seed is a whole number, like 1234."),
+        }
+    }
+}
+
+// Daily Challenge runs go to their own board rather than the Hall of Fame
+fn record_run(
+    player: &dyn Fighter,
+    encounters: u32,
+    gold: u32,
+    cause: String,
+    seed: u64,
+    mode: RunMode,
+    daily: Option<&DailyChallenge>,
+) {
+    let run = RunRecord {
+        name: player.get_name().to_string(),
+        class: player.class(),
+        encounters,
+        gold,
+        cause,
+        seed,
+        talents: player.talents().ids(),
+        mode,
+    };
+    match daily {
+        Some(challenge) => match DailyLeaderboard::record(challenge.date(), run) {
+            Ok(()) => println!("📅 Your run has been posted to today's daily leaderboard."),
+            Err(err) => eprintln!("⚠️  Could not save to the daily leaderboard: {}", err),
+        },
+        None => match Leaderboard::record(run) {
+            Ok(()) => println!("🏆 Your tale has been recorded in the Hall of Fame."),
+            Err(err) => eprintln!("⚠️  Could not save to the Hall of Fame: {}", err),
+        },
+    }
+}
+
+// For reading only: a damaged profile is reported, and the run goes on without it
+fn read_profile() -> Profile {
+    Profile::load().unwrap_or_else(|err| {
+        eprintln!("⚠️  Could not read your profile: {}", err);
+        Profile::default()
+    })
+}
+
+// Turns a finished run into embers to spend on unlocks
+fn award_embers(encounters: u32, bosses: u32) {
+    let embers = embers_earned(encounters, bosses);
+    if embers == 0 {
+        return;
+    }
+    let mut profile = match Profile::load() {
+        Ok(profile) => profile,
+        Err(err) => {
+            eprintln!("⚠️  Could not save your embers: {}", err);
+            return;
+        }
+    };
+    profile.add_embers(embers);
+    match profile.save() {
+        Ok(()) => println!(
+            "🔥 You earned {} embers! ({} to spend on unlocks)",
+            embers,
+            profile.embers()
+        ),
+        Err(err) => eprintln!("⚠️  Could not save your embers: {}", err),
+    }
+}
+
+fn unlock_menu() {
+    loop {
+        let mut profile = match Profile::load() {
+            Ok(profile) => profile,
+            Err(err) => {
+                eprintln!("⚠️  Could not open the Ember Forge: {}", err);
+                return;
+            }
+        };
+
+        println!("\n┌────────────────────────────────────────────────────────────┐");
+        println!("│  🔥 {:<55}│", "EMBER FORGE");
+        println!("│  {:<58}│", format!("Embers: {}", profile.embers()));
+        println!("├────────────────────────────────────────────────────────────┤");
+        for (i, unlock) in UNLOCKS.iter().enumerate() {
+            let status = if profile.has_unlock(unlock.id) {
+                "\x1b[92m✓ Unlocked\x1b[0m".to_string()
+            } else {
+                format!("{} embers", unlock.cost)
+            };
+            println!("│  [{}] {:<20} {}│", i + 1, unlock.name, pad(&status, 33));
+            println!("│      {:<54}│", unlock.description);
+        }
+        println!("├────────────────────────────────────────────────────────────┤");
+        println!(
+            "│  {:<58}│",
+            "Earn embers for every encounter survived and boss slain"
+        );
+        println!("│  [0] Back                                                  │");
+        println!("└────────────────────────────────────────────────────────────┘");
+        print!("\n➤ Choose an unlock to buy (or 0 to go back): ");
+        let _ = io::stdout().flush();
+
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input).is_err() {
+            eprintln!("Failed to read input");
+            continue;
+        }
+
+        match input.trim().parse::<usize>() {
+            Ok(0) => return,
+            Ok(num) if num <= UNLOCKS.len() => {
+                let unlock = &UNLOCKS[num - 1];
+                if profile.has_unlock(unlock.id) {
+                    println!("\n⚠️  You have already unlocked {}.", unlock.name);
+                } else if !profile.buy(unlock) {
+                    println!(
+                        "\n⚠️  {} costs {} embers; you have {}.",
+                        unlock.name,
+                        unlock.cost,
+                        profile.embers()
+                    );
+                } else {
+                    match profile.save() {
+                        Ok(()) => println!("\n🔥 {} unlocked!", unlock.name),
+                        Err(err) => eprintln!("⚠️  Could not save your unlock: {}", err),
+                    }
+                }
+                std::thread::sleep(std::time::Duration::from_millis(800));
+            }
+            _ => {
+                println!("Invalid choice. Please try again.");
+                std::thread::sleep(std::time::Duration::from_millis(800));
+            }
+        }
+    }
+}
+
+fn offer_stats_export(chronicle: &Chronicle) {
+    println!("┌────────────────────────────────────────┐");
+    println!("│  [1] 💾 Export run stats to JSON       │");
+    println!("│  [2] Continue                          │");
+    println!("└────────────────────────────────────────┘");
+    print!("\n➤ Choose (1-2): ");
+    let _ = io::stdout().flush();
+
+    if get_user_choice(2) == 1 {
+        match chronicle.stats().export() {
+            Ok(path) => println!("💾 Run stats written to {}", path),
+            Err(err) => eprintln!("⚠️  Could not export run stats: {}", err),
+        }
+    }
+}
+
+// One attempt per day: it is spent the moment the run begins
+fn daily_challenge() -> Result<()> {
+    let challenge = DailyChallenge::today();
+    let leaderboard = match DailyLeaderboard::load() {
+        Ok(leaderboard) => leaderboard,
+        Err(err) => {
+            eprintln!("⚠️  Could not read the daily leaderboard: {}", err);
+            return Ok(());
+        }
+    };
+    show_daily_challenge(&challenge, &leaderboard.ranked(challenge.date()));
+
+    let mut profile = match Profile::load() {
+        Ok(profile) => profile,
+        Err(err) => {
+            eprintln!("⚠️  Your attempt can't be recorded: {}", err);
+            return Ok(());
+        }
+    };
+    if profile.has_attempted_daily(challenge.date()) {
+        println!("\n✓ You have already made today's attempt. Return tomorrow for a new challenge.");
+        return Ok(());
+    }
+
+    println!("┌────────────────────────────────────────┐");
+    println!("│  [1] Begin today's attempt             │");
+    println!("│  [2] Back                              │");
+    println!("└────────────────────────────────────────┘");
+    print!("\n➤ Choose (1-2): ");
+    let _ = io::stdout().flush();
+
+    if get_user_choice(2) == 1 {
+        profile.mark_daily_attempt(challenge.date());
+        profile.save()?;
+        // Daily runs ignore unlocks so everyone starts equal
+        run_game(challenge.seed(), &Profile::default(), Some(&challenge))?;
+    }
+    Ok(())
+}
+
+fn hall_of_fame() {
+    let leaderboard = match Leaderboard::load() {
+        Ok(leaderboard) => leaderboard,
+        Err(err) => {
+            eprintln!("⚠️  Could not read the Hall of Fame: {}", err);
+            return;
+        }
+    };
+    let mut ranking = Ranking::Encounters;
+    let mut class: Option<Class> = None;
+    let mut mode = RunMode::Adventure;
+
+    loop {
+        let runs = leaderboard.ranked(ranking, class, mode);
+        show_hall_of_fame(
+            &runs,
+            ranking,
+            class.map_or("All", |class| class.name()),
+            mode,
+        );
+        println!("┌────────────────────────────────────────┐");
+        println!("│  [1] Rank by {:<26}│", ranking.next().name());
+        println!("│  [2] Next class filter                 │");
+        println!("│  [3] Show {:<29}│", mode.next().name());
+        println!("│  [4] Back                              │");
+        println!("└────────────────────────────────────────┘");
+        print!("\n➤ Choose (1-4): ");
+        let _ = io::stdout().flush();
+
+        match get_user_choice(4) {
+            1 => ranking = ranking.next(),
+            3 => mode = mode.next(),
+            2 => {
+                class = match class {
+                    None => Some(Class::ALL[0]),
+                    Some(current) => Class::ALL
+                        .iter()
+                        .skip_while(|&&class| class != current)
+                        .nth(1)
+                        .copied(),
+                }
+            }
+            _ => return,
         }
     }
 }
@@ -277,35 +1684,127 @@ fn get_user_choice(max: u32) -> u32 {
     }
 }
 
-fn spawn_enemy(encounter: u32) -> Enemy {
-    let mut rng = rand::rng();
+// The beaten boss's fate is the player's to decide; returns true if it was spared
+fn decide_boss_fate(boss: &Boss, player: &mut Box<dyn Fighter>, world: &mut WorldState) -> bool {
+    const MERCY_EXPERIENCE: u32 = 75;
+
+    println!(
+        "\n\x1b[95m{} lies broken before you, breath rattling in its throat.\x1b[0m",
+        boss.title()
+    );
+    println!("┌────────────────────────────────────────┐");
+    println!("│  [1] 🗡️  Slay it and claim every trophy │");
+    println!("│  [2] 🕊️  Spare it and let it flee       │");
+    println!("└────────────────────────────────────────┘");
+    print!("\n➤ Choose (1-2): ");
+    let _ = io::stdout().flush();
+
+    if get_user_choice(2) == 1 {
+        world.increment(BOSSES_SLAIN);
+        println!(
+            "\n\x1b[91mYour blade falls. {} will trouble the world no more.\x1b[0m",
+            boss.title()
+        );
+        false
+    } else {
+        world.increment(BOSSES_SPARED);
+        println!(
+            "\n\x1b[96m{} drags itself into the dark, shedding a single scale as it goes.\x1b[0m",
+            boss.title()
+        );
+        player.gain_experience(MERCY_EXPERIENCE);
+        true
+    }
+}
+
+fn enemy_level(encounter: u32, location: &Location) -> u32 {
+    1 + (encounter / 2) + (location.danger() - 1) / 2
+}
 
-    // Boss every 5 encounters
-    if encounter % 5 == 0 {
-        let level = encounter / 5 + 2;
-        return Enemy::dragon(level);
+fn spawn_enemy(
+    encounter: u32,
+    location: &Location,
+    world: &WorldState,
+    rng: &mut impl Rng,
+) -> Enemy {
+    // Roll against the location's spawn table with level scaling
+    let level = enemy_level(encounter, location);
+    let table: Vec<(EnemyKind, u32)> = location
+        .native_enemies()
+        .iter()
+        .map(|&(kind, weight)| {
+            // Restless spirits thin out once Sister Maren is laid to rest
+            if kind == EnemyKind::Specter && world.has_flag(MAREN_AT_PEACE) {
+                (kind, weight / 4)
+            } else {
+                (kind, weight)
+            }
+        })
+        .collect();
+    let total_weight: u32 = table.iter().map(|(_, weight)| weight).sum();
+    let mut roll = rng.random_range(1..=total_weight);
+
+    for &(kind, weight) in &table {
+        if roll <= weight {
+            return Enemy::new(kind, level);
+        }
+        roll -= weight;
     }
 
-    // Random enemy with level scaling
-    let level = 1 + (encounter / 2);
-    let enemy_type = rng.random_range(1..=100);
+    Enemy::new(table[0].0, level)
+}
 
-    if enemy_type <= 50 {
-        Enemy::goblin(level)
-    } else if enemy_type <= 85 {
-        Enemy::orc(level)
-    } else {
-        Enemy::dragon(level)
-    }
+// A blizzard's chill lasts only as long as the battle fought in it
+fn battle(
+    player: &mut Box<dyn Fighter>,
+    enemy: &mut Enemy,
+    boss: Option<&mut Boss>,
+    location: Location,
+    inventory: &mut Inventory,
+    party: &mut Party,
+    chronicle: &mut Chronicle,
+) -> bool {
+    player.set_chill(Hazard::at(&location).map_or(0, |hazard| hazard.agility_loss()));
+    let won = fight(player, enemy, boss, location, inventory, party, chronicle);
+    player.set_chill(0);
+    won
 }
 
-fn battle(player: &mut Box<dyn Fighter>, enemy: &mut Enemy, inventory: &mut Vec<Item>) -> bool {
+fn fight(
+    player: &mut Box<dyn Fighter>,
+    enemy: &mut Enemy,
+    mut boss: Option<&mut Boss>,
+    location: Location,
+    inventory: &mut Inventory,
+    party: &mut Party,
+    chronicle: &mut Chronicle,
+) -> bool {
     println!("\n╔════════════════════════════════════════╗");
     println!("║        ⚔️  BATTLE START! ⚔️            ║");
     println!("╚════════════════════════════════════════╝\n");
 
+    const DEFEND_DAMAGE_REDUCTION: f32 = 0.5;
+    const COUNTER_CHANCE: u32 = 35;
+    const POISON_DAMAGE: u32 = 5;
+    const POISON_TURNS: u32 = 4;
+
+    let hazard = Hazard::at(&location);
+    if let Some(hazard) = hazard {
+        println!(
+            "\x1b[93m{} {}: {}\x1b[0m",
+            hazard.icon(),
+            hazard.name(),
+            hazard.description()
+        );
+    }
+
+    if let Some(boss) = boss.as_deref_mut() {
+        boss.begin(enemy);
+    }
+
     loop {
         // Player turn
+        let mut defending = false;
         let ability_cost = player.special_ability_cost();
         let can_use = player.can_use_special();
         let ability_name = player.special_ability_name();
@@ -313,6 +1812,12 @@ fn battle(player: &mut Box<dyn Fighter>, enemy: &mut Enemy, inventory: &mut Vec<
 
         println!("\n┌────────────────────────────────────────┐");
         println!("│  YOUR TURN                             │");
+        if let Some(boss) = boss.as_deref() {
+            println!("│  👑 Phase: {:<28}│", boss.phase_name());
+        }
+        if let Some(hazard) = hazard {
+            println!("│  {} {:<35}│", hazard.icon(), hazard.name());
+        }
         println!("├────────────────────────────────────────┤");
         print_health_bar("You", player.get_health(), player.get_max_health());
         print_resource_bar(
@@ -320,7 +1825,23 @@ fn battle(player: &mut Box<dyn Fighter>, enemy: &mut Enemy, inventory: &mut Vec<
             player.get_resource(),
             player.get_max_resource(),
         );
+        if !player.effects().is_empty() {
+            let effects: Vec<String> = player
+                .effects()
+                .iter()
+                .map(|effect| format!("{} ({})", effect.kind.name(), effect.battles))
+                .collect();
+            println!("│  ✨ {}", effects.join(", "));
+        }
+        for ally in party.allies() {
+            print_health_bar(ally.get_name(), ally.get_health(), ally.get_max_health());
+        }
         print_health_bar(enemy.get_name(), enemy.get_health(), enemy.get_max_health());
+        if let Some(boss) = boss.as_deref() {
+            for add in boss.adds() {
+                print_health_bar(add.get_name(), add.get_health(), add.get_max_health());
+            }
+        }
         println!("├────────────────────────────────────────┤");
         println!("│  [1] ⚔️  Attack                        │");
 
@@ -336,110 +1857,553 @@ fn battle(player: &mut Box<dyn Fighter>, enemy: &mut Enemy, inventory: &mut Vec<
             );
         }
 
-        println!("│  [3] 🧪 Potion                         │");
-        println!("│  [4] 🎒 Inventory                      │");
+        // Classes that gain nothing from guarding (the Necromancer) get no resource line
+        if player.defend_resource_gain() > 0 {
+            println!(
+                "│  [3] 🛡️  Defend (+{} {})                │",
+                player.defend_resource_gain(),
+                resource_name
+            );
+        } else {
+            println!("│  [3] 🛡️  Defend                        │");
+        }
+        println!("│  [4] 🧪 Potion                         │");
+        println!("│  [5] 🎒 Inventory                      │");
+        let summon = AllyKind::summoned_by(player.class());
+        if let Some((kind, cost)) = summon {
+            println!(
+                "│  [6] {} {:<33}│",
+                kind.icon(),
+                format!("Summon {} (-{} {})", kind.name(), cost, resource_name)
+            );
+        }
         println!("└────────────────────────────────────────┘");
-        print!("\n➤ Action (1-4): ");
+        let max_choice = if summon.is_some() { 6 } else { 5 };
+        print!("\n➤ Action (1-{}): ", max_choice);
         let _ = io::stdout().flush();
 
-        match get_user_choice(4) {
+        // Summoned adds shield the boss, so strikes land on them first
+        let target: &mut Enemy = match boss.as_deref_mut().and_then(Boss::front_add_mut) {
+            Some(add) => add,
+            None => enemy,
+        };
+
+        // Affixes on carried gear sharpen every blow
+        let might = 1.0 + total_bonus(inventory, AffixStat::Might) as f32 / 100.0;
+        let lifesteal = total_bonus(inventory, AffixStat::Lifesteal);
+        let guard = 1.0 - total_bonus(inventory, AffixStat::Guard) as f32 / 100.0;
+        let mut dealt = 0;
+        let mut critical = false;
+        let mut wasted = false;
+
+        let choice = get_user_choice(max_choice);
+        match choice {
             1 => {
-                let mut rng = rand::rng();
+                let mut rng = rng::rng();
                 let base_damage = player.attack();
-                let variance = rng.random_range(0.85..=1.15);
+                let damage_type = player.attack_damage_type();
+                let variance = rng.random_range(0.85..=1.15) * player.attack_modifier() * might;
                 let mut damage = (base_damage as f32 * variance) as u32;
+                damage = hazard_damage(hazard, damage, damage_type);
 
                 // Critical hit chance
                 let crit_chance = rng.random_range(1..=100);
                 if crit_chance > 85 {
+                    critical = true;
                     damage = (damage as f32 * 1.5) as u32;
                     println!("\n\x1b[91m⚡ CRITICAL HIT! ⚡\x1b[0m");
-                    println!("💥 You attack for \x1b[93m{} damage!\x1b[0m", damage);
+                    println!(
+                        "💥 You attack for \x1b[93m{} {} damage!\x1b[0m",
+                        damage,
+                        damage_type.name()
+                    );
                 } else {
-                    println!("\n💥 You attack for {} damage!", damage);
+                    println!(
+                        "\n💥 You attack for {} {} {} damage!",
+                        damage,
+                        damage_type.icon(),
+                        damage_type.name()
+                    );
                 }
-                enemy.take_damage(damage);
+                let damage = apply_affinity(&*target, damage, damage_type);
+                target.take_damage(damage);
+                dealt = damage;
             }
             2 => {
                 if let Some(base_damage) = player.special_ability() {
-                    let mut rng = rand::rng();
-                    let variance = rng.random_range(0.9..=1.1);
+                    let mut rng = rng::rng();
+                    let variance = rng.random_range(0.9..=1.1) * player.attack_modifier() * might;
                     let damage = (base_damage as f32 * variance) as u32;
-                    enemy.take_damage(damage);
+                    let damage_type = player.special_damage_type();
+                    let damage = hazard_damage(hazard, damage, damage_type);
+                    let damage = apply_affinity(&*target, damage, damage_type);
+                    target.take_damage(damage);
+                    dealt = damage;
                 } else {
+                    wasted = true;
                     println!("\n⚠️  Turn wasted!");
                 }
             }
             3 => {
-                if use_health_potion(player, inventory) {
-                    println!("\n✨ You used a Health Potion!");
-                } else {
-                    println!("\n❌ No Health Potions available! Turn wasted.");
+                defending = true;
+                let before = player.get_resource();
+                player.set_resource(before + player.defend_resource_gain());
+                print!("\n🛡️  You raise your guard and brace for the next blow!");
+                if player.defend_resource_gain() > 0 {
+                    print!(
+                        " +{} {} ({}/{})",
+                        player.get_resource() - before,
+                        player.get_resource_name(),
+                        player.get_resource(),
+                        player.get_max_resource()
+                    );
                 }
+                println!();
             }
-            4 => {
-                if show_and_use_inventory(player, inventory) {
+            4 => match use_health_potion(player, inventory, target, chronicle) {
+                Some(true) => {}
+                // Refused, e.g. at full health; choose again
+                Some(false) => continue,
+                None => {
+                    wasted = true;
+                    println!("\n❌ No Health Potions available! Turn wasted.");
+                }
+            },
+            5 => {
+                if show_and_use_inventory(player, inventory, target, chronicle) {
                     // Item was used, end turn
                 } else {
                     // No item used, don't end turn
                     continue;
                 }
             }
+            6 => {
+                let Some((kind, cost)) = summon else {
+                    unreachable!()
+                };
+                if !party.has_room_for(kind) {
+                    println!("\n❌ There is no room in your party for a {}!", kind.name());
+                    continue;
+                }
+                if !player.consume_resource(cost) {
+                    println!(
+                        "\n❌ Not enough {} to summon a {}!",
+                        player.get_resource_name(),
+                        kind.name()
+                    );
+                    continue;
+                }
+                party.recruit(kind, player.get_level());
+                println!(
+                    "\n{} A {} bursts into being at your side!",
+                    kind.icon(),
+                    kind.name()
+                );
+            }
             _ => unreachable!(),
         }
+        let action = match choice {
+            1 => CombatAction::Attack,
+            2 => CombatAction::Special,
+            3 => CombatAction::Defend,
+            6 => CombatAction::Summon,
+            _ => CombatAction::Item,
+        };
+        if !wasted {
+            track_event(chronicle, &GameEvent::CombatActionTaken(action));
+        }
+        if dealt > 0 {
+            track_event(
+                chronicle,
+                &GameEvent::DamageDealt {
+                    amount: dealt,
+                    critical,
+                },
+            );
+        }
+
+        let drained = dealt * lifesteal / 100;
+        if drained > 0 && player.get_health() < player.get_max_health() {
+            print!("🩸 ");
+            player.heal(drained);
+        }
 
         // Check if enemy is defeated
         if !enemy.is_alive() {
             println!("\n🎉 {} has been defeated!", enemy.get_name());
+            player.on_kill();
             return true;
         }
 
+        if let Some(boss) = boss.as_deref_mut() {
+            resolve_boss_wounds(boss, enemy, player);
+        }
+
+        // Allies act after the player
+        for ally in party.allies() {
+            let target: &mut Enemy = match boss.as_deref_mut().and_then(Boss::front_add_mut) {
+                Some(add) => add,
+                None => enemy,
+            };
+            let (damage, flavor) = ally.strike();
+            let damage_type = ally.damage_type();
+            println!(
+                "{} {} {} for {} {} {} damage!",
+                ally.kind().icon(),
+                ally.get_name(),
+                flavor,
+                damage,
+                damage_type.icon(),
+                damage_type.name()
+            );
+            let damage = apply_affinity(&*target, damage, damage_type);
+            target.take_damage(damage);
+
+            if !enemy.is_alive() {
+                println!("\n🎉 {} has been defeated!", enemy.get_name());
+                player.on_kill();
+                return true;
+            }
+
+            if let Some(boss) = boss.as_deref_mut() {
+                resolve_boss_wounds(boss, enemy, player);
+            }
+        }
+        for ally in party.tick_summons() {
+            println!(
+                "{} The {} flickers and fades away.",
+                ally.kind().icon(),
+                ally.get_name()
+            );
+        }
+
         // Enemy turn
         println!("\n╔════════════════════════════════════════╗");
         println!("║          ENEMY TURN                    ║");
         println!("╚════════════════════════════════════════╝");
 
-        let mut rng = rand::rng();
-        let base_damage = enemy.attack();
-        let variance = rng.random_range(0.8..=1.2);
-        let mut damage = (base_damage as f32 * variance) as u32;
+        let mut strikes = match boss.as_deref_mut() {
+            Some(boss) => boss.strikes(enemy),
+            None => vec![Strike::basic(enemy)],
+        };
+        if let Some(boss) = boss.as_deref() {
+            strikes.extend(boss.adds().iter().map(Strike::basic));
+        }
+
+        let mut rng = rng::rng();
+        for strike in strikes {
+            let damage_type = strike.damage_type;
+
+            // Allies draw some of the blows meant for the player
+            if let Some(index) = party.pick_target() {
+                let ally = &mut party.allies_mut()[index];
+                let damage = (strike.damage as f32 * rng.random_range(0.8..=1.2)) as u32;
+                println!(
+                    "💥 {} {} at the {} for {} {} {} damage!",
+                    strike.attacker,
+                    strike.flavor,
+                    ally.get_name(),
+                    damage,
+                    damage_type.icon(),
+                    damage_type.name()
+                );
+                ally.take_damage(damage);
+                for fallen in party.bury_fallen() {
+                    println!(
+                        "\x1b[91m☠️  {} {} has fallen!\x1b[0m",
+                        fallen.kind().icon(),
+                        fallen.get_name()
+                    );
+                }
+                continue;
+            }
+
+            let variance = rng.random_range(0.8..=1.2) * player.damage_taken_modifier() * guard;
+            let mut damage = (strike.damage as f32 * variance) as u32;
+
+            // Enemy critical hit chance (lower than player)
+            let crit_chance = rng.random_range(1..=100);
+            let critical = crit_chance > 92;
+            if critical {
+                damage = (damage as f32 * 1.5) as u32;
+                println!(
+                    "\n\x1b[91m⚡ {} lands a CRITICAL HIT! ⚡\x1b[0m",
+                    strike.attacker
+                );
+                println!(
+                    "💥 You take \x1b[91m{} {} damage!\x1b[0m",
+                    damage,
+                    damage_type.name()
+                );
+            } else {
+                println!(
+                    "💥 {} {} for {} {} {} damage!",
+                    strike.attacker,
+                    strike.flavor,
+                    damage,
+                    damage_type.icon(),
+                    damage_type.name()
+                );
+            }
+            damage = apply_affinity(&**player, damage, damage_type);
+
+            if defending {
+                damage = (damage as f32 * (1.0 - DEFEND_DAMAGE_REDUCTION)) as u32;
+                println!(
+                    "🛡️  Your guard absorbs the blow! Damage reduced to {}.",
+                    damage
+                );
+            }
+            player.take_damage(damage);
+            track_event(
+                chronicle,
+                &GameEvent::DamageTaken {
+                    amount: damage,
+                    critical,
+                },
+            );
+
+            if !player.is_alive() {
+                return false;
+            }
+        }
 
-        // Enemy critical hit chance (lower than player)
-        let crit_chance = rng.random_range(1..=100);
-        if crit_chance > 92 {
-            damage = (damage as f32 * 1.5) as u32;
+        // A successful guard can open the enemy up to a riposte
+        if defending && rng.random_range(1..=100) <= COUNTER_CHANCE {
+            let target: &mut Enemy = match boss.as_deref_mut().and_then(Boss::front_add_mut) {
+                Some(add) => add,
+                None => enemy,
+            };
+            let counter_damage =
+                (player.attack() as f32 * player.attack_modifier() * might) as u32 / 2;
+            let counter_damage = hazard_damage(hazard, counter_damage, player.attack_damage_type());
             println!(
-                "\n\x1b[91m⚡ {} lands a CRITICAL HIT! ⚡\x1b[0m",
-                enemy.get_name()
+                "\n\x1b[96m↩️  COUNTER-ATTACK! You strike back for {} damage!\x1b[0m",
+                counter_damage
             );
-            println!("💥 You take \x1b[91m{} damage!\x1b[0m", damage);
-        } else {
-            println!("💥 {} attacks for {} damage!", enemy.get_name(), damage);
+            let counter_damage =
+                apply_affinity(&*target, counter_damage, player.attack_damage_type());
+            target.take_damage(counter_damage);
+            track_event(
+                chronicle,
+                &GameEvent::DamageDealt {
+                    amount: counter_damage,
+                    critical: false,
+                },
+            );
+
+            if !enemy.is_alive() {
+                println!("\n🎉 {} has been defeated!", enemy.get_name());
+                player.on_kill();
+                return true;
+            }
+
+            if let Some(boss) = boss.as_deref_mut() {
+                resolve_boss_wounds(boss, enemy, player);
+            }
         }
-        player.take_damage(damage);
 
-        // Check if player is defeated
-        if !player.is_alive() {
-            return false;
+        // The land itself takes its toll at the end of the turn
+        let poisoned = |player: &dyn Fighter| {
+            player
+                .effects()
+                .iter()
+                .any(|effect| effect.kind == EffectKind::Poisoned)
+        };
+        if let Some(hazard) = hazard {
+            if !poisoned(&**player) && rng.random_range(1..=100) <= hazard.poison_chance() {
+                println!(
+                    "\n\x1b[92m🦠 The miasma seeps into your wounds - you are poisoned!\x1b[0m"
+                );
+                player.apply_effect(StatusEffect::new(EffectKind::Poisoned, POISON_TURNS));
+            }
+        }
+        let mut toll = 0;
+        if poisoned(&**player) {
+            println!("\x1b[92m🦠 Poison burns through your veins!\x1b[0m");
+            toll += POISON_DAMAGE;
+            player.tick_turn_effects();
+        }
+        if let Some(hazard) = hazard.filter(|hazard| hazard.burn() > 0) {
+            println!(
+                "\n\x1b[91m{} The searing heat scorches everyone!\x1b[0m",
+                hazard.icon()
+            );
+            toll += apply_affinity(&**player, hazard.burn(), DamageType::Fire);
+            let burn = apply_affinity(&*enemy, hazard.burn(), DamageType::Fire);
+            enemy.take_damage(burn);
+            if let Some(boss) = boss.as_deref_mut() {
+                for add in boss.adds_mut() {
+                    let burn = apply_affinity(&*add, hazard.burn(), DamageType::Fire);
+                    add.take_damage(burn);
+                }
+            }
+            for ally in party.allies_mut() {
+                ally.take_damage(hazard.burn());
+            }
+            for fallen in party.bury_fallen() {
+                println!(
+                    "\x1b[91m☠️  {} {} has fallen!\x1b[0m",
+                    fallen.kind().icon(),
+                    fallen.get_name()
+                );
+            }
+        }
+        if toll > 0 {
+            player.take_damage(toll);
+            track_event(
+                chronicle,
+                &GameEvent::DamageTaken {
+                    amount: toll,
+                    critical: false,
+                },
+            );
+            if !player.is_alive() {
+                return false;
+            }
+        }
+        if !enemy.is_alive() {
+            println!("\n🎉 {} has been defeated!", enemy.get_name());
+            player.on_kill();
+            return true;
+        }
+        if let Some(boss) = boss.as_deref_mut() {
+            resolve_boss_wounds(boss, enemy, player);
         }
     }
 }
 
-fn use_health_potion(player: &mut Box<dyn Fighter>, inventory: &mut Vec<Item>) -> bool {
-    const POTION_HEAL: u32 = 30;
-    if let Some(pos) = inventory
-        .iter()
-        .position(|item| item.name() == "Health Potion")
-    {
-        inventory.remove(pos);
-        player.heal(POTION_HEAL);
-        true
-    } else {
-        false
+// Holy power swells on hallowed ground
+fn hazard_damage(hazard: Option<Hazard>, damage: u32, damage_type: DamageType) -> u32 {
+    hazard.map_or(damage, |hazard| {
+        (damage as f32 * hazard.damage_modifier(damage_type)) as u32
+    })
+}
+
+// Clears slain adds and advances the boss through any phase thresholds it crossed
+fn resolve_boss_wounds(boss: &mut Boss, enemy: &mut Enemy, player: &mut Box<dyn Fighter>) {
+    for add in boss.adds().iter().filter(|add| !add.is_alive()) {
+        println!("\n🎉 {} has been defeated!", add.get_name());
+        player.on_kill();
+    }
+    boss.clear_fallen_adds();
+    boss.check_phase(enemy);
+}
+
+fn apply_affinity<T: Character + Combat + ?Sized>(
+    target: &T,
+    damage: u32,
+    damage_type: DamageType,
+) -> u32 {
+    let affinity = target.affinity(damage_type);
+    if let Some(note) = affinity.describe(target.get_name(), damage_type) {
+        println!("{}", note);
+    }
+    affinity.apply(damage)
+}
+
+// Quick-drinks the first healing item in the bag
+// None if there is no potion to drink, otherwise whether it was drunk
+fn use_health_potion(
+    player: &mut Box<dyn Fighter>,
+    inventory: &mut Inventory,
+    target: &mut Enemy,
+    chronicle: &mut Chronicle,
+) -> Option<bool> {
+    let slot = inventory.stacks().iter().position(|stack| {
+        stack
+            .item()
+            .consumable()
+            .is_some_and(|consumable| consumable.only_heals())
+    })?;
+    Some(use_item(player, inventory, slot, Some(target), chronicle))
+}
+
+// Every menu uses items through here; a target is only present in battle.
+// Returns true if the item was used up
+fn use_item(
+    player: &mut Box<dyn Fighter>,
+    inventory: &mut Inventory,
+    slot: usize,
+    target: Option<&mut Enemy>,
+    chronicle: &mut Chronicle,
+) -> bool {
+    let item = inventory.stacks()[slot].item();
+    let Some(consumable) = item.consumable() else {
+        println!("\n📜 {}", item.display_name());
+        println!("   Rarity: {}", item.rarity().name());
+        println!("   Value: {} gold", item.value());
+        for affix in item.affixes() {
+            println!("   ✦ {}: {}", affix.name, affix.stat.describe(affix.bonus));
+        }
+        println!("   This item cannot be used.");
+        return false;
+    };
+    if let Err(reason) = consumable.check(&**player, target.is_some()) {
+        println!("\n⚠️  {}", reason.describe());
+        return false;
     }
+
+    inventory.take(slot);
+    println!("\n✨ You {} the {}!", consumable.verb, consumable.name);
+    track_event(chronicle, &GameEvent::ItemUsed(consumable.name));
+
+    let mut target = target;
+    for effect in consumable.effects {
+        match *effect {
+            ItemEffect::Heal(amount) => player.heal(amount),
+            ItemEffect::RestoreResource(amount) => {
+                let restored = player.restore_resource(amount);
+                println!(
+                    "⚡ Restored {} {}! ({}/{})",
+                    restored,
+                    player.get_resource_name(),
+                    player.get_resource(),
+                    player.get_max_resource()
+                );
+            }
+            ItemEffect::Buff(kind, battles) => {
+                player.apply_effect(StatusEffect::new(kind, battles));
+            }
+            ItemEffect::Cure => {
+                player
+                    .effects_mut()
+                    .retain(|effect| !effect.kind.is_harmful());
+                println!("🌿 Your afflictions wash away.");
+            }
+            ItemEffect::DamageEnemy(amount, damage_type) => {
+                if let Some(target) = target.as_deref_mut() {
+                    println!(
+                        "💥 It bursts for {} {} {} damage!",
+                        amount,
+                        damage_type.icon(),
+                        damage_type.name()
+                    );
+                    let damage = apply_affinity(&*target, amount, damage_type);
+                    target.take_damage(damage);
+                    track_event(
+                        chronicle,
+                        &GameEvent::DamageDealt {
+                            amount: damage,
+                            critical: false,
+                        },
+                    );
+                }
+            }
+        }
+    }
+    true
 }
 
-fn use_inventory_menu(player: &mut Box<dyn Fighter>, inventory: &mut Vec<Item>, gold: u32) {
+fn use_inventory_menu(
+    player: &mut Box<dyn Fighter>,
+    inventory: &mut Inventory,
+    gold: u32,
+    chronicle: &mut Chronicle,
+) {
+    let mut sort_key = SortKey::Kind;
+    let mut filter = Filter::All;
+
     loop {
         println!("\n┌────────────────────────────────────────┐");
         println!("│  🎒 INVENTORY                          │");
@@ -454,6 +2418,10 @@ fn use_inventory_menu(player: &mut Box<dyn Fighter>, inventory: &mut Vec<Item>,
             player.get_resource_name(),
             format!("{}/{}", player.get_resource(), player.get_max_resource())
         );
+        println!(
+            "│  🎒 Slots: {:<29}│",
+            format!("{}/{}", inventory.len(), BAG_CAPACITY)
+        );
         println!("├────────────────────────────────────────┤");
 
         if inventory.is_empty() {
@@ -468,97 +2436,242 @@ fn use_inventory_menu(player: &mut Box<dyn Fighter>, inventory: &mut Vec<Item>,
             return;
         }
 
-        for (i, item) in inventory.iter().enumerate() {
-            let usable = if item.is_health_potion() {
-                if player.get_health() < player.get_max_health() {
-                    "✓ Use"
-                } else {
-                    "✗ Full HP"
-                }
-            } else if item.is_energy_potion() {
-                if player.get_resource() < player.get_max_resource() {
-                    "✓ Use"
-                } else {
-                    "✗ Full"
-                }
-            } else {
-                "View"
+        let visible = inventory.visible(filter);
+        if visible.is_empty() {
+            println!("│  (Nothing matches this filter)         │");
+        }
+        for (i, &slot) in visible.iter().enumerate() {
+            let stack = &inventory.stacks()[slot];
+            let usable = match stack.item().consumable() {
+                Some(consumable) => match consumable.check(&**player, false) {
+                    Ok(()) => "✓ Use",
+                    Err(reason) => reason.label(),
+                },
+                None => "View",
             };
-            println!("│  [{}] {:<25} {:>7} │", i + 1, item.name(), usable);
+            println!("│  [{}] {:<25} {:>7} │", i + 1, stack.label(), usable);
         }
 
         println!("├────────────────────────────────────────┤");
+        println!("│  [S] Sort by {:<26}│", sort_key.name());
+        println!("│  [F] Filter: {:<26}│", filter.name());
+        println!("│  [D] Drop an item                      │");
         println!("│  [0] Back to Menu                      │");
         println!("└────────────────────────────────────────┘");
-        print!("\n➤ Choose item to use (or 0 to go back): ");
+        print!("\n➤ Choose item to use, S/F/D (or 0 to go back): ");
         let _ = io::stdout().flush();
 
-        let max_choice = inventory.len() as u32;
         let mut input = String::new();
         if io::stdin().read_line(&mut input).is_err() {
             eprintln!("Failed to read input");
             continue;
         }
 
-        match input.trim().parse::<u32>() {
+        match input.trim().to_lowercase().as_str() {
+            "s" => {
+                inventory.sort(sort_key);
+                sort_key = sort_key.next();
+                continue;
+            }
+            "f" => {
+                filter = filter.next();
+                continue;
+            }
+            "d" => {
+                drop_item(inventory, &visible);
+                continue;
+            }
+            _ => {}
+        }
+
+        match input.trim().parse::<usize>() {
             Ok(0) => return, // Back to menu
-            Ok(num) if num >= 1 && num <= max_choice => {
-                let index = (num - 1) as usize;
-                let item = &inventory[index];
+            Ok(num) if num <= visible.len() => {
+                use_item(player, inventory, visible[num - 1], None, chronicle);
+                std::thread::sleep(std::time::Duration::from_millis(1200));
+            }
+            _ => {
+                println!("Invalid choice. Please try again.");
+                std::thread::sleep(std::time::Duration::from_millis(800));
+            }
+        }
+    }
+}
 
-                if item.is_health_potion() {
-                    if player.get_health() >= player.get_max_health() {
-                        println!("\n⚠️  You're already at full health!");
-                        std::thread::sleep(std::time::Duration::from_millis(1000));
-                        continue;
-                    }
-                    const POTION_HEAL: u32 = 30;
-                    inventory.remove(index);
-                    player.heal(POTION_HEAL);
-                    println!(
-                        "\n✨ You drink the Health Potion and restore {} HP!",
-                        POTION_HEAL
-                    );
-                    println!(
-                        "   Current Health: {}/{}",
-                        player.get_health(),
-                        player.get_max_health()
-                    );
-                    std::thread::sleep(std::time::Duration::from_millis(1200));
-                } else if item.is_energy_potion() {
-                    if player.get_resource() >= player.get_max_resource() {
-                        println!("\n⚠️  Your {} is already full!", player.get_resource_name());
-                        std::thread::sleep(std::time::Duration::from_millis(1000));
-                        continue;
-                    }
-                    const RESOURCE_RESTORE: u32 = 40;
-                    let resource_name = player.get_resource_name().to_string();
-                    inventory.remove(index);
+// Throws away one of the listed stacks
+fn drop_item(inventory: &mut Inventory, visible: &[usize]) {
+    if visible.is_empty() {
+        return;
+    }
+    print!(
+        "\n➤ Drop which item? (1-{}, or 0 to cancel): ",
+        visible.len()
+    );
+    let _ = io::stdout().flush();
 
-                    let current = player.get_resource();
-                    let max = player.get_max_resource();
-                    let new_resource = (current + RESOURCE_RESTORE).min(max);
-                    let actual_restore = new_resource - current;
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input).is_err() {
+        return;
+    }
+    if let Ok(num @ 1..) = input.trim().parse::<usize>() {
+        if let Some(stack) = visible
+            .get(num - 1)
+            .and_then(|&slot| inventory.drop_slot(slot))
+        {
+            println!("\n🗑️  You leave the {} by the roadside.", stack.label());
+            std::thread::sleep(std::time::Duration::from_millis(800));
+        }
+    }
+}
 
-                    player.set_resource(new_resource);
+// Puts an item in the bag; when it is full the player can drop a stack to make room.
+// Returns false if the new item was left behind
+fn stash(inventory: &mut Inventory, item: Item) -> bool {
+    let item = match inventory.add(item) {
+        Ok(()) => return true,
+        Err(item) => item,
+    };
+
+    println!(
+        "\n🎒 Your bag is full! ({}/{} slots)",
+        inventory.len(),
+        BAG_CAPACITY
+    );
+    println!("┌────────────────────────────────────────┐");
+    println!(
+        "│  New: {:<33}│",
+        format!("{} ({}g)", item.display_name(), item.value())
+    );
+    println!("├────────────────────────────────────────┤");
+    for (i, stack) in inventory.stacks().iter().enumerate() {
+        println!(
+            "│  [{}] Drop {:<22} {:>4}g │",
+            i + 1,
+            stack.label(),
+            stack.item().value()
+        );
+    }
+    let leave = inventory.len() as u32 + 1;
+    println!("│  [{}] {:<34}│", leave, "Leave the new item behind");
+    println!("└────────────────────────────────────────┘");
+    print!("\n➤ Choose (1-{}): ", leave);
+    let _ = io::stdout().flush();
 
-                    println!(
-                        "\n✨ You drink the Energy Potion and restore {} {}!",
-                        actual_restore, resource_name
-                    );
-                    println!(
-                        "   Current {}: {}/{}",
-                        resource_name,
-                        player.get_resource(),
-                        player.get_max_resource()
-                    );
-                    std::thread::sleep(std::time::Duration::from_millis(1200));
+    let choice = get_user_choice(leave);
+    if choice == leave {
+        println!("\n💨 You leave the {} behind.", item.display_name());
+        return false;
+    }
+    if let Some(stack) = inventory.drop_slot((choice - 1) as usize) {
+        println!("\n🗑️  You drop the {} to make room.", stack.label());
+    }
+    inventory.add(item).is_ok()
+}
+
+fn talent_menu(player: &mut Box<dyn Fighter>, chronicle: &mut Chronicle) {
+    loop {
+        let talents: Vec<&'static Talent> = Talent::for_class(player.class()).collect();
+        let points = player.talent_points();
+
+        println!("\n┌────────────────────────────────────────────────────────────┐");
+        println!(
+            "│  🌟 {:<55}│",
+            format!("{} TALENTS", player.class().name().to_uppercase())
+        );
+        println!("│  {:<58}│", format!("Points to spend: {}", points));
+        println!("├────────────────────────────────────────────────────────────┤");
+        for (i, talent) in talents.iter().enumerate() {
+            let status = if player.talents().has(talent.id) {
+                "\x1b[92m✓ Learned\x1b[0m".to_string()
+            } else if !player.talents().is_unlocked(talent) {
+                let required = talents
+                    .iter()
+                    .find(|other| talent.requires == Some(other.id))
+                    .map_or("an earlier talent", |other| other.name);
+                format!("\x1b[90m🔒 Needs {}\x1b[0m", required)
+            } else {
+                "Available".to_string()
+            };
+            println!("│  [{}] {:<20} {}│", i + 1, talent.name, pad(&status, 33));
+            println!("│      {:<54}│", talent.description);
+        }
+        println!("├────────────────────────────────────────────────────────────┤");
+        println!("│  {:<58}│", "Earn a talent point with every level gained");
+        println!("│  [0] Back to Menu                                          │");
+        println!("└────────────────────────────────────────────────────────────┘");
+        print!("\n➤ Choose a talent to learn (or 0 to go back): ");
+        let _ = io::stdout().flush();
+
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input).is_err() {
+            eprintln!("Failed to read input");
+            continue;
+        }
+
+        match input.trim().parse::<usize>() {
+            Ok(0) => return,
+            Ok(num) if num <= talents.len() => {
+                let talent = talents[num - 1];
+                if player.talents().has(talent.id) {
+                    println!("\n⚠️  You have already learned {}.", talent.name);
+                } else if !player.talents().is_unlocked(talent) {
+                    println!("\n⚠️  {} builds on an earlier talent.", talent.name);
+                } else if points == 0 {
+                    println!("\n⚠️  You have no talent points. Level up to earn more.");
                 } else {
-                    println!("\n📜 {}", item.name());
-                    println!("   Value: {} gold", item.value());
-                    println!("   This item cannot be used right now.");
-                    std::thread::sleep(std::time::Duration::from_millis(1200));
+                    player.learn_talent(talent);
+                    track_event(chronicle, &GameEvent::TalentLearned(talent.id));
+                }
+                std::thread::sleep(std::time::Duration::from_millis(800));
+            }
+            _ => {
+                println!("Invalid choice. Please try again.");
+                std::thread::sleep(std::time::Duration::from_millis(800));
+            }
+        }
+    }
+}
+
+fn crafting_menu(inventory: &mut Inventory) {
+    loop {
+        println!("\n┌────────────────────────────────────────┐");
+        println!("│  ⚒️  CRAFTING                           │");
+        println!("├────────────────────────────────────────┤");
+        for (i, recipe) in RECIPES.iter().enumerate() {
+            let status = if recipe.can_craft(inventory) {
+                "\x1b[92m✓ Ready\x1b[0m"
+            } else {
+                "\x1b[90m✗ Missing\x1b[0m"
+            };
+            println!("│  [{}] {:<25} {}", i + 1, recipe.name, status);
+            println!("│      Needs: {}", recipe.requirements(inventory));
+        }
+        println!("├────────────────────────────────────────┤");
+        println!("│  [0] Back to Menu                      │");
+        println!("└────────────────────────────────────────┘");
+        print!("\n➤ Choose recipe to craft (or 0 to go back): ");
+        let _ = io::stdout().flush();
+
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input).is_err() {
+            eprintln!("Failed to read input");
+            continue;
+        }
+
+        match input.trim().parse::<usize>() {
+            Ok(0) => return,
+            Ok(num) if num <= RECIPES.len() => {
+                let recipe: &Recipe = &RECIPES[num - 1];
+                match recipe.craft(inventory) {
+                    Some(item) => {
+                        println!("\n⚒️  You craft a \x1b[96m{}\x1b[0m!", item.display_name());
+                        stash(inventory, item);
+                    }
+                    None => {
+                        println!("\n⚠️  You don't have the materials for {}.", recipe.name);
+                    }
                 }
+                std::thread::sleep(std::time::Duration::from_millis(1000));
             }
             _ => {
                 println!("Invalid choice. Please try again.");
@@ -568,7 +2681,12 @@ fn use_inventory_menu(player: &mut Box<dyn Fighter>, inventory: &mut Vec<Item>,
     }
 }
 
-fn show_and_use_inventory(player: &mut Box<dyn Fighter>, inventory: &mut Vec<Item>) -> bool {
+fn show_and_use_inventory(
+    player: &mut Box<dyn Fighter>,
+    inventory: &mut Inventory,
+    target: &mut Enemy,
+    chronicle: &mut Chronicle,
+) -> bool {
     loop {
         println!("\n┌────────────────────────────────────────┐");
         println!("│  🎒 INVENTORY                          │");
@@ -585,8 +2703,13 @@ fn show_and_use_inventory(player: &mut Box<dyn Fighter>, inventory: &mut Vec<Ite
             return false;
         }
 
-        for (i, item) in inventory.iter().enumerate() {
-            println!("│  [{}] {:<30} {}g │", i + 1, item.name(), item.value());
+        for (i, stack) in inventory.stacks().iter().enumerate() {
+            println!(
+                "│  [{}] {:<30} {}g │",
+                i + 1,
+                stack.label(),
+                stack.item().value()
+            );
         }
 
         println!("├────────────────────────────────────────┤");
@@ -606,35 +2729,10 @@ fn show_and_use_inventory(player: &mut Box<dyn Fighter>, inventory: &mut Vec<Ite
             Ok(0) => return false, // Back to combat without using item
             Ok(num) if num >= 1 && num <= max_choice => {
                 let index = (num - 1) as usize;
-                let item = &inventory[index];
-
-                if item.is_health_potion() {
-                    const POTION_HEAL: u32 = 30;
-                    inventory.remove(index);
-                    player.heal(POTION_HEAL);
-                    println!("\n✨ You used a Health Potion!");
-                    return true; // Item used, end turn
-                } else if item.is_energy_potion() {
-                    const RESOURCE_RESTORE: u32 = 40;
-                    let resource_name = player.get_resource_name().to_string();
-                    inventory.remove(index);
-
-                    let current = player.get_resource();
-                    let max = player.get_max_resource();
-                    let new_resource = (current + RESOURCE_RESTORE).min(max);
-
-                    player.set_resource(new_resource);
-
-                    println!(
-                        "\n✨ You used an Energy Potion! Restored {} {}!",
-                        new_resource - current,
-                        resource_name
-                    );
+                if use_item(player, inventory, index, Some(&mut *target), chronicle) {
                     return true; // Item used, end turn
-                } else {
-                    println!("\n⚠️  You can't use that item right now!");
-                    continue;
                 }
+                continue;
             }
             _ => {
                 println!("Invalid choice. Please try again.");
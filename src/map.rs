@@ -0,0 +1,104 @@
+use crate::narrative::Location;
+
+// The lair is reached on every Nth encounter, whichever road the player takes
+pub const LAIR_INTERVAL: u32 = 5;
+
+// Unlockable places and the locations whose roads branch off to them
+const HIDDEN_PATHS: &[(Location, &[Location])] = &[(
+    Location::SunkenTemple,
+    &[Location::ShadowMarsh, Location::AbandonedRuins],
+)];
+
+pub struct WorldMap {
+    current: Option<Location>,
+    unlocked: Vec<Location>,
+}
+
+impl WorldMap {
+    pub fn new(unlocked: Vec<Location>) -> Self {
+        WorldMap {
+            current: None,
+            unlocked,
+        }
+    }
+
+    pub fn current(&self) -> Option<Location> {
+        self.current
+    }
+
+    // Destinations reachable from where the player stands
+    pub fn routes(&self, encounter: u32) -> Vec<Location> {
+        if encounter.is_multiple_of(LAIR_INTERVAL) {
+            return vec![Location::DragonLair];
+        }
+
+        let paths: &[Location] = match self.current {
+            None | Some(Location::DragonLair) => &[
+                Location::DarkForest,
+                Location::AbandonedRuins,
+                Location::MountainPass,
+            ],
+            Some(Location::DarkForest) => &[
+                Location::AbandonedRuins,
+                Location::MountainPass,
+                Location::ShadowMarsh,
+            ],
+            Some(Location::AbandonedRuins) => &[
+                Location::DarkForest,
+                Location::HauntedCrypt,
+                Location::MountainPass,
+            ],
+            Some(Location::MountainPass) => &[
+                Location::FrozenWastes,
+                Location::VolcanicCavern,
+                Location::AbandonedRuins,
+            ],
+            Some(Location::HauntedCrypt) => &[
+                Location::ShadowMarsh,
+                Location::AbandonedRuins,
+                Location::VolcanicCavern,
+            ],
+            Some(Location::VolcanicCavern) => &[
+                Location::MountainPass,
+                Location::HauntedCrypt,
+                Location::FrozenWastes,
+            ],
+            Some(Location::FrozenWastes) => &[
+                Location::MountainPass,
+                Location::DarkForest,
+                Location::ShadowMarsh,
+            ],
+            Some(Location::ShadowMarsh) => &[
+                Location::DarkForest,
+                Location::HauntedCrypt,
+                Location::FrozenWastes,
+            ],
+            Some(Location::SunkenTemple) => &[
+                Location::ShadowMarsh,
+                Location::AbandonedRuins,
+                Location::HauntedCrypt,
+            ],
+        };
+        let mut routes = paths.to_vec();
+        for &(hidden, from) in HIDDEN_PATHS {
+            let reachable = self.current.is_some_and(|current| from.contains(&current));
+            if reachable && self.unlocked.contains(&hidden) {
+                routes.push(hidden);
+            }
+        }
+        routes
+    }
+
+    pub fn travel(&mut self, destination: Location) {
+        self.current = Some(destination);
+    }
+
+    pub fn encounters_until_lair(encounter: u32) -> u32 {
+        let remainder = encounter % LAIR_INTERVAL;
+        if remainder == 0 {
+            0
+        } else {
+            LAIR_INTERVAL - remainder
+        }
+    }
+}
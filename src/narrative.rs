@@ -1,5 +1,19 @@
 use rand::Rng;
 
+use crate::ally::AllyKind;
+use crate::character::EffectKind;
+use crate::clock::TimeOfDay;
+use crate::enemy::EnemyKind;
+use crate::rng;
+use crate::world::{WorldState, BOSSES_SLAIN, BOSSES_SPARED, OMENS_SEEN, PRAYERS};
+
+// Prayers it takes before the gods answer with greater blessings
+const FAITHFUL_PRAYERS: u32 = 4;
+// How much daylight improves searching and praying
+const DAYLIGHT_BONUS: u32 = 15;
+// Chance that something finds a hero resting in the dark
+const NIGHT_REST_AMBUSH: u32 = 35;
+
 pub struct Narrative;
 
 impl Narrative {
@@ -12,8 +26,17 @@ impl Narrative {
          ╚═══════════════════════════════════════════════════════════╝\x1b[0m\n"
     }
 
-    pub fn random_travel_verse() -> &'static str {
-        let mut rng = rand::rng();
+    pub fn dungeon_verse() -> &'static str {
+        "\n\x1b[36m╔═══════════════════════════════════════════════════════════╗\n\
+         ║  Beneath the ruins, stairs spiral down into the dark,     ║\n\
+         ║  Where wyrms brood over hoards no sun has touched.        ║\n\
+         ║  Each floor runs deeper, and none who delve return        ║\n\
+         ║  the same as they went down.                              ║\n\
+         ╚═══════════════════════════════════════════════════════════╝\x1b[0m\n"
+    }
+
+    pub fn random_travel_verse(time: TimeOfDay) -> String {
+        let mut rng = rng::rng();
         let verses = [
             "\n\x1b[90mThe path winds through mist and ancient stone.\n\
              You travel alone with only your thoughts and blade.\x1b[0m\n",
@@ -26,11 +49,16 @@ impl Narrative {
             "\n\x1b[90mThrough valleys carved by time and mountain peaks lost in clouds.\n\
              This is the path of heroes, and you walk it now.\x1b[0m\n",
         ];
-        verses[rng.random_range(0..verses.len())]
+        format!(
+            "{}\x1b[90m{} {}\x1b[0m\n",
+            verses[rng.random_range(0..verses.len())],
+            time.icon(),
+            time.verse()
+        )
     }
 
     pub fn victory_verse() -> &'static str {
-        let mut rng = rand::rng();
+        let mut rng = rng::rng();
         let verses = [
             "\n\x1b[33mSteel sings its song of finality.\n\
              The beast crumples, and silence returns to the world.\x1b[0m\n",
@@ -53,15 +81,35 @@ impl Narrative {
          ╚═══════════════════════════════════════════════════════════╝\x1b[0m\n"
     }
 
-    pub fn boss_approach_verse() -> &'static str {
-        "\n\x1b[35m⚠ The air grows heavy with ancient malevolence.\n\
-         A great evil awaits just ahead.\n\
-         Steel yourself. Destiny approaches.\n\
-         What happens next will be remembered. ⚠\x1b[0m\n"
+    pub fn boss_approach_verse(world: &WorldState) -> &'static str {
+        let slain = world.count(BOSSES_SLAIN);
+        let spared = world.count(BOSSES_SPARED);
+
+        if slain > 0 && spared > 0 {
+            "\n\x1b[35m⚠ The wyrms speak your name in two voices now.\n\
+             Some curse the blade that slew their kin; some recall the mercy you showed.\n\
+             Which of you walks into the lair today?\n\
+             What happens next will be remembered. ⚠\x1b[0m\n"
+        } else if slain > 0 {
+            "\n\x1b[35m⚠ Bones of a slain wyrm hang from your pack, and the lair smells them.\n\
+             Something ahead has been waiting for the kinslayer.\n\
+             It will not be taken by surprise again.\n\
+             It remembers. ⚠\x1b[0m\n"
+        } else if spared > 0 {
+            "\n\x1b[35m⚠ The air grows heavy, yet the dark seems to hesitate.\n\
+             Word of the wyrm you spared has traveled ahead of you.\n\
+             Mercy was remembered. Will it be repaid?\n\
+             What happens next will be remembered. ⚠\x1b[0m\n"
+        } else {
+            "\n\x1b[35m⚠ The air grows heavy with ancient malevolence.\n\
+             A great evil awaits just ahead.\n\
+             Steel yourself. Destiny approaches.\n\
+             What happens next will be remembered. ⚠\x1b[0m\n"
+        }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Location {
     DarkForest,
     AbandonedRuins,
@@ -70,28 +118,56 @@ pub enum Location {
     VolcanicCavern,
     FrozenWastes,
     ShadowMarsh,
+    SunkenTemple,
     DragonLair,
 }
 
 impl Location {
-    pub fn random(encounter: u32) -> Self {
-        let mut rng = rand::rng();
-
-        if encounter > 0 && encounter % 5 == 0 {
-            return Location::DragonLair;
+    pub fn name(&self) -> &'static str {
+        match self {
+            Location::DarkForest => "The Dark Forest",
+            Location::AbandonedRuins => "Abandoned Ruins",
+            Location::MountainPass => "Mountain Pass",
+            Location::HauntedCrypt => "Haunted Crypt",
+            Location::VolcanicCavern => "Volcanic Cavern",
+            Location::FrozenWastes => "Frozen Wastes",
+            Location::ShadowMarsh => "Shadow Marsh",
+            Location::SunkenTemple => "Sunken Temple",
+            Location::DragonLair => "Dragon's Lair",
         }
+    }
 
-        let locations = [
-            Location::DarkForest,
-            Location::AbandonedRuins,
-            Location::MountainPass,
-            Location::HauntedCrypt,
-            Location::VolcanicCavern,
-            Location::FrozenWastes,
-            Location::ShadowMarsh,
-        ];
+    // 1 (safe-ish) to 5 (deadly): raises enemy levels and the gold to be found
+    pub fn danger(&self) -> u32 {
+        match self {
+            Location::DarkForest => 1,
+            Location::AbandonedRuins | Location::MountainPass => 2,
+            Location::HauntedCrypt | Location::ShadowMarsh | Location::SunkenTemple => 3,
+            Location::VolcanicCavern | Location::FrozenWastes => 4,
+            Location::DragonLair => 5,
+        }
+    }
 
-        locations[rng.random_range(0..locations.len())].clone()
+    // What scouts and rumors say awaits the traveler; companions only if they could join
+    pub fn route_hints(&self, can_recruit: impl Fn(AllyKind) -> bool) -> Vec<&'static str> {
+        let mut hints: Vec<&'static str> = self
+            .available_actions()
+            .iter()
+            .filter(|action| action.companion().is_none_or(&can_recruit))
+            .filter_map(|action| match action {
+                LocationAction::Rest => Some("🏕️ rest spot"),
+                LocationAction::Pray => Some("🙏 shrine"),
+                LocationAction::ReadRunes => Some("📜 runes"),
+                LocationAction::Prepare => Some("⚔️ final stand"),
+                LocationAction::TameWolf => Some("🐺 lone wolf"),
+                LocationAction::RaiseDead => Some("💀 restless dead"),
+                _ => None,
+            })
+            .collect();
+        if self.danger() >= 3 {
+            hints.push("💰 rich caches");
+        }
+        hints
     }
 
     pub fn description(&self) -> &str {
@@ -131,6 +207,11 @@ impl Location {
                  Thick fog obscures your vision as you wade through murky waters.\n\
                  Strange creatures lurk beneath the surface, and the smell of decay is overwhelming."
             }
+            Location::SunkenTemple => {
+                "\x1b[34m🛕 SUNKEN TEMPLE 🛕\x1b[0m\n\
+                 Half-drowned colonnades rise from black water, their carvings worn smooth by the tide.\n\
+                 Candles still burn on the altar, though no living priest has tended them in an age."
+            }
             Location::DragonLair => {
                 "\x1b[91m🐉 DRAGON'S LAIR 🐉\x1b[0m\n\
                  You stand before a massive cavern entrance, scorched earth all around.\n\
@@ -149,16 +230,65 @@ impl Location {
             Location::VolcanicCavern => "A beast born of flame approaches...",
             Location::FrozenWastes => "An icy horror materializes from the blizzard...",
             Location::ShadowMarsh => "The waters ripple as something rises...",
+            Location::SunkenTemple => "Something stirs beneath the flooded altar...",
             Location::DragonLair => "The ground shakes. Wings unfold in the darkness. IT AWAKENS.",
         }
     }
 
+    // Weighted spawn table of the creatures native to each location
+    pub fn native_enemies(&self) -> &'static [(EnemyKind, u32)] {
+        match self {
+            Location::DarkForest => &[
+                (EnemyKind::Goblin, 40),
+                (EnemyKind::DireWolf, 40),
+                (EnemyKind::Orc, 20),
+            ],
+            Location::AbandonedRuins => &[
+                (EnemyKind::Cultist, 45),
+                (EnemyKind::StoneGolem, 30),
+                (EnemyKind::Goblin, 25),
+            ],
+            Location::MountainPass => &[
+                (EnemyKind::Orc, 45),
+                (EnemyKind::Harpy, 40),
+                (EnemyKind::Dragon, 15),
+            ],
+            Location::HauntedCrypt => &[
+                (EnemyKind::Skeleton, 45),
+                (EnemyKind::Ghoul, 35),
+                (EnemyKind::Specter, 20),
+            ],
+            Location::VolcanicCavern => &[
+                (EnemyKind::FireElemental, 50),
+                (EnemyKind::MagmaHound, 35),
+                (EnemyKind::Dragon, 15),
+            ],
+            Location::FrozenWastes => &[
+                (EnemyKind::IceWraith, 50),
+                (EnemyKind::FrostTroll, 35),
+                (EnemyKind::DireWolf, 15),
+            ],
+            Location::ShadowMarsh => &[
+                (EnemyKind::BogHorror, 45),
+                (EnemyKind::MarshHag, 35),
+                (EnemyKind::Goblin, 20),
+            ],
+            Location::SunkenTemple => &[
+                (EnemyKind::Cultist, 40),
+                (EnemyKind::BogHorror, 30),
+                (EnemyKind::Specter, 30),
+            ],
+            Location::DragonLair => &[(EnemyKind::Dragon, 100)],
+        }
+    }
+
     pub fn available_actions(&self) -> Vec<LocationAction> {
         match self {
             Location::DarkForest => vec![
                 LocationAction::Search,
                 LocationAction::Rest,
                 LocationAction::Investigate,
+                LocationAction::TameWolf,
             ],
             Location::AbandonedRuins => vec![
                 LocationAction::Search,
@@ -170,16 +300,22 @@ impl Location {
                 LocationAction::Search,
                 LocationAction::Pray,
                 LocationAction::Investigate,
+                LocationAction::RaiseDead,
             ],
             Location::VolcanicCavern => vec![LocationAction::Search, LocationAction::Investigate],
             Location::FrozenWastes => vec![LocationAction::Search, LocationAction::Rest],
             Location::ShadowMarsh => vec![LocationAction::Search, LocationAction::Investigate],
+            Location::SunkenTemple => vec![
+                LocationAction::Search,
+                LocationAction::Pray,
+                LocationAction::ReadRunes,
+            ],
             Location::DragonLair => vec![LocationAction::Search, LocationAction::Prepare],
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LocationAction {
     Search,
     Rest,
@@ -187,6 +323,8 @@ pub enum LocationAction {
     ReadRunes,
     Pray,
     Prepare,
+    TameWolf,
+    RaiseDead,
 }
 
 impl LocationAction {
@@ -198,28 +336,51 @@ impl LocationAction {
             LocationAction::ReadRunes => "Attempt to read the ancient runes",
             LocationAction::Pray => "Pray to the forgotten gods",
             LocationAction::Prepare => "Prepare for the ultimate battle",
+            LocationAction::TameWolf => "Approach the lone wolf",
+            LocationAction::RaiseDead => "Raise a fallen warrior",
+        }
+    }
+
+    // How long the action takes
+    pub fn hours(&self) -> u32 {
+        match self {
+            LocationAction::Rest => 4,
+            LocationAction::Search => 2,
+            _ => 1,
+        }
+    }
+
+    // The ally this action brings into the party, if any
+    pub fn companion(&self) -> Option<AllyKind> {
+        match self {
+            LocationAction::TameWolf => Some(AllyKind::Wolf),
+            LocationAction::RaiseDead => Some(AllyKind::Skeleton),
+            _ => None,
         }
     }
 
     pub fn execute(
         &self,
+        location: &Location,
         player: &mut Box<dyn crate::character::traits::Fighter>,
         gold: &mut u32,
+        world: &mut WorldState,
     ) -> String {
-        let mut rng = rand::rng();
+        let mut rng = rng::rng();
 
         match self {
             LocationAction::Search => {
-                let luck = rng.random_range(1..=100);
+                let daylight = if world.clock().is_day() { DAYLIGHT_BONUS } else { 0 };
+                let luck = rng.random_range(1..=100) + daylight;
                 if luck > 70 {
-                    let found_gold = rng.random_range(10..=30);
+                    let found_gold = rng.random_range(10..=30) + location.danger() * 5;
                     *gold += found_gold;
                     format!(
                         "\x1b[33m✨ You discover a hidden cache! Found {} gold coins!\x1b[0m",
                         found_gold
                     )
                 } else if luck > 40 {
-                    format!("\x1b[90mYou search carefully but find nothing of value.\x1b[0m")
+                    "\x1b[90mYou search carefully but find nothing of value.\x1b[0m".to_string()
                 } else {
                     let damage = rng.random_range(5..=15);
                     player.take_damage(damage);
@@ -230,6 +391,14 @@ impl LocationAction {
                 }
             }
             LocationAction::Rest => {
+                if world.clock().is_night() && rng.random_range(1..=100) <= NIGHT_REST_AMBUSH {
+                    let damage = rng.random_range(8..=18);
+                    player.take_damage(damage);
+                    return format!(
+                        "\x1b[31m🌙 Something finds you in the dark and bites before fleeing! Lost {} health.\x1b[0m",
+                        damage
+                    );
+                }
                 let heal_amount = rng.random_range(15..=25);
                 player.heal(heal_amount);
                 format!(
@@ -240,33 +409,44 @@ impl LocationAction {
             LocationAction::Investigate => {
                 let discovery = rng.random_range(1..=4);
                 match discovery {
-                    1 => format!("\x1b[36mYou notice strange markings on the ground. They seem to form a warning...\x1b[0m"),
-                    2 => format!("\x1b[36mYou find traces of a previous battle. Whoever fought here did not survive.\x1b[0m"),
+                    1 => "\x1b[36mYou notice strange markings on the ground. They seem to form a warning...\x1b[0m".to_string(),
+                    2 => "\x1b[36mYou find traces of a previous battle. Whoever fought here did not survive.\x1b[0m".to_string(),
                     3 => {
                         player.heal(10);
-                        format!("\x1b[32mYou discover a natural spring and drink deeply. Restored 10 health.\x1b[0m")
+                        "\x1b[32mYou discover a natural spring and drink deeply. Restored 10 health.\x1b[0m".to_string()
                     }
-                    _ => format!("\x1b[36mYou learn the lay of the land, feeling more prepared for what's ahead.\x1b[0m"),
+                    _ => "\x1b[36mYou learn the lay of the land, feeling more prepared for what's ahead.\x1b[0m".to_string(),
                 }
             }
             LocationAction::ReadRunes => {
-                let understanding = rng.random_range(1..=100);
+                // Omens witnessed on the road help make sense of the runes
+                let understanding = rng.random_range(1..=100) + world.count(OMENS_SEEN) * 5;
                 if understanding > 60 {
-                    let resource_gain = 20;
+                    let restored = player.restore_resource(20);
                     format!(
                         "\x1b[35m✨ The ancient magic resonates with you! Restored {} {}!\x1b[0m",
-                        resource_gain,
+                        restored,
                         player.get_resource_name()
                     )
                 } else {
-                    format!(
-                        "\x1b[90mThe runes are too ancient, their meaning lost to time...\x1b[0m"
-                    )
+                    "\x1b[90mThe runes are too ancient, their meaning lost to time...\x1b[0m"
+                        .to_string()
                 }
             }
             LocationAction::Pray => {
-                let blessing = rng.random_range(1..=100);
-                if blessing > 75 {
+                // The forgotten gods grow to know the voice of the faithful
+                let prayers = world.increment(PRAYERS);
+                let devotion = (prayers - 1).min(4) * 10;
+                let daylight = if world.clock().is_day() { DAYLIGHT_BONUS } else { 0 };
+                let blessing = rng.random_range(1..=100) + devotion + daylight;
+                if prayers >= FAITHFUL_PRAYERS && blessing > 75 {
+                    let heal_amount = rng.random_range(35..=55);
+                    player.heal(heal_amount);
+                    format!(
+                        "\x1b[93m✨ The forgotten gods know your voice now. Warmth floods you - restored {} health!\x1b[0m",
+                        heal_amount
+                    )
+                } else if blessing > 75 {
                     let heal_amount = rng.random_range(20..=40);
                     player.heal(heal_amount);
                     format!(
@@ -274,34 +454,288 @@ impl LocationAction {
                         heal_amount
                     )
                 } else if blessing > 30 {
-                    format!("\x1b[90mYour prayers echo in the silence. Perhaps the gods are watching...\x1b[0m")
+                    "\x1b[90mYour prayers echo in the silence. Perhaps the gods are watching...\x1b[0m".to_string()
                 } else {
-                    format!("\x1b[90mOnly silence answers your prayers.\x1b[0m")
+                    "\x1b[90mOnly silence answers your prayers.\x1b[0m".to_string()
                 }
             }
             LocationAction::Prepare => {
                 player.heal(30);
-                format!("\x1b[93m⚔️ You steel your nerves and prepare for the battle ahead. Restored 30 health.\x1b[0m")
+                "\x1b[93m⚔️ You steel your nerves and prepare for the battle ahead. Restored 30 health.\x1b[0m".to_string()
+            }
+            LocationAction::TameWolf => {
+                "\x1b[36m🐺 You kneel and offer your rations. The wolf sniffs your hand, then falls in beside you.\x1b[0m".to_string()
+            }
+            LocationAction::RaiseDead => {
+                "\x1b[35m💀 You speak the old words over a broken tomb. Bones rattle and rise to serve you.\x1b[0m".to_string()
             }
         }
     }
 }
 
-pub struct RandomEvent;
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventOutcome {
+    Gold(u32),
+    Item(&'static str, u32),
+    Heal(u32),
+    Damage(u32),
+    Effect(EffectKind, u32), // Buff, debuff or curse lasting a number of battles
+    Ambush(EnemyKind),
+}
 
-impl RandomEvent {
-    pub fn try_trigger() -> Option<String> {
-        let mut rng = rand::rng();
+// One possible result of a choice, picked by weight
+#[derive(Debug)]
+pub struct EventResult {
+    pub weight: u32,
+    pub text: &'static str,
+    pub outcomes: &'static [EventOutcome],
+}
 
+#[derive(Debug)]
+pub struct EventChoice {
+    pub text: &'static str,
+    pub results: &'static [EventResult],
+}
+
+impl EventChoice {
+    pub fn roll(&self, rng: &mut impl Rng) -> &EventResult {
+        let total_weight: u32 = self.results.iter().map(|result| result.weight).sum();
+        let mut roll = rng.random_range(1..=total_weight);
+        for result in self.results {
+            if roll <= result.weight {
+                return result;
+            }
+            roll -= result.weight;
+        }
+        &self.results[0]
+    }
+}
+
+#[derive(Debug)]
+pub struct RandomEvent {
+    pub text: &'static str,
+    pub locations: &'static [Location], // Empty means the event can happen anywhere
+    pub choices: &'static [EventChoice],
+}
+
+const NOTHING_HAPPENS: EventResult = EventResult {
+    weight: 1,
+    text: "\x1b[90mYou continue on your way.\x1b[0m",
+    outcomes: &[],
+};
+
+const RANDOM_EVENTS: &[RandomEvent] = &[
+    RandomEvent {
+        text: "\x1b[36m🌟 A shooting star streaks across the sky.\x1b[0m",
+        locations: &[],
+        choices: &[
+            EventChoice {
+                text: "Make a wish",
+                results: &[EventResult {
+                    weight: 1,
+                    text: "\x1b[36mYou feel strangely invigorated.\x1b[0m",
+                    outcomes: &[EventOutcome::Effect(EffectKind::Invigorated, 2)],
+                }],
+            },
+            EventChoice {
+                text: "Keep walking",
+                results: &[NOTHING_HAPPENS],
+            },
+        ],
+    },
+    RandomEvent {
+        text: "\x1b[90m🦅 A raven perches nearby, watching you with intelligent eyes.\x1b[0m",
+        locations: &[],
+        choices: &[
+            EventChoice {
+                text: "Follow where it flies",
+                results: &[
+                    EventResult {
+                        weight: 60,
+                        text: "\x1b[33mThe raven leads you to a dead traveler's purse.\x1b[0m",
+                        outcomes: &[EventOutcome::Gold(40)],
+                    },
+                    EventResult {
+                        weight: 40,
+                        text: "\x1b[31mThe raven leads you straight into a wolf den!\x1b[0m",
+                        outcomes: &[EventOutcome::Ambush(EnemyKind::DireWolf)],
+                    },
+                ],
+            },
+            EventChoice {
+                text: "Shoo it away",
+                results: &[NOTHING_HAPPENS],
+            },
+        ],
+    },
+    RandomEvent {
+        text: "\x1b[33m🔥 You come across an abandoned campfire, still warm.\x1b[0m",
+        locations: &[],
+        choices: &[
+            EventChoice {
+                text: "Rest by the embers",
+                results: &[EventResult {
+                    weight: 1,
+                    text: "\x1b[32mThe warmth soaks into your tired bones.\x1b[0m",
+                    outcomes: &[EventOutcome::Heal(20)],
+                }],
+            },
+            EventChoice {
+                text: "Rummage through the packs",
+                results: &[
+                    EventResult {
+                        weight: 55,
+                        text: "\x1b[33mTucked in a bedroll, you find a Health Potion.\x1b[0m",
+                        outcomes: &[EventOutcome::Item("Health Potion", 50)],
+                    },
+                    EventResult {
+                        weight: 45,
+                        text: "\x1b[31mThe owners return - and they are not pleased!\x1b[0m",
+                        outcomes: &[EventOutcome::Ambush(EnemyKind::Goblin)],
+                    },
+                ],
+            },
+        ],
+    },
+    RandomEvent {
+        text: "\x1b[32m🌿 You notice strange mushrooms glowing faintly in the darkness.\x1b[0m",
+        locations: &[Location::DarkForest, Location::ShadowMarsh],
+        choices: &[
+            EventChoice {
+                text: "Pick them for later",
+                results: &[EventResult {
+                    weight: 1,
+                    text: "\x1b[32mYou carefully wrap a cluster of the mushrooms.\x1b[0m",
+                    outcomes: &[EventOutcome::Item("Glowing Mushroom", 30)],
+                }],
+            },
+            EventChoice {
+                text: "Eat one",
+                results: &[
+                    EventResult {
+                        weight: 60,
+                        text: "\x1b[32mA pleasant warmth spreads through you.\x1b[0m",
+                        outcomes: &[
+                            EventOutcome::Heal(25),
+                            EventOutcome::Effect(EffectKind::Invigorated, 1),
+                        ],
+                    },
+                    EventResult {
+                        weight: 40,
+                        text: "\x1b[31mYour stomach lurches. That was a mistake.\x1b[0m",
+                        outcomes: &[
+                            EventOutcome::Damage(10),
+                            EventOutcome::Effect(EffectKind::Weakened, 2),
+                        ],
+                    },
+                ],
+            },
+            EventChoice {
+                text: "Leave them be",
+                results: &[NOTHING_HAPPENS],
+            },
+        ],
+    },
+    RandomEvent {
+        text: "\x1b[35m👻 You hear distant laughter, though no one is there...\x1b[0m",
+        locations: &[Location::HauntedCrypt, Location::AbandonedRuins],
+        choices: &[
+            EventChoice {
+                text: "Call out to the voice",
+                results: &[EventResult {
+                    weight: 1,
+                    text: "\x1b[35mThe laughter stops. Something cold settles on your shoulders.\x1b[0m",
+                    outcomes: &[EventOutcome::Effect(EffectKind::Cursed, 3)],
+                }],
+            },
+            EventChoice {
+                text: "Whisper a warding prayer",
+                results: &[EventResult {
+                    weight: 1,
+                    text: "\x1b[93mThe air clears. You feel watched over.\x1b[0m",
+                    outcomes: &[EventOutcome::Effect(EffectKind::Warded, 2)],
+                }],
+            },
+        ],
+    },
+    RandomEvent {
+        text: "\x1b[33m🍂 A cold wind blows, carrying whispers of forgotten names.\x1b[0m",
+        locations: &[Location::FrozenWastes, Location::MountainPass],
+        choices: &[
+            EventChoice {
+                text: "Listen closely",
+                results: &[
+                    EventResult {
+                        weight: 50,
+                        text: "\x1b[36mAmong the names, you hear a warning - and heed it.\x1b[0m",
+                        outcomes: &[EventOutcome::Effect(EffectKind::Warded, 2)],
+                    },
+                    EventResult {
+                        weight: 50,
+                        text: "\x1b[31mThe whispers gnaw at your resolve.\x1b[0m",
+                        outcomes: &[EventOutcome::Effect(EffectKind::Weakened, 1)],
+                    },
+                ],
+            },
+            EventChoice {
+                text: "Pull your cloak tight and press on",
+                results: &[NOTHING_HAPPENS],
+            },
+        ],
+    },
+    RandomEvent {
+        text: "\x1b[31m🌋 Gems glitter inside a hissing lava vent.\x1b[0m",
+        locations: &[Location::VolcanicCavern],
+        choices: &[
+            EventChoice {
+                text: "Reach in and grab one",
+                results: &[EventResult {
+                    weight: 1,
+                    text: "\x1b[33mYou snatch a Gem, but the heat blisters your hand.\x1b[0m",
+                    outcomes: &[EventOutcome::Item("Gem", 90), EventOutcome::Damage(15)],
+                }],
+            },
+            EventChoice {
+                text: "Leave it to the fire",
+                results: &[NOTHING_HAPPENS],
+            },
+        ],
+    },
+    RandomEvent {
+        text: "\x1b[33m🌫️ The corner of a chest pokes out of the black water.\x1b[0m",
+        locations: &[Location::ShadowMarsh],
+        choices: &[
+            EventChoice {
+                text: "Wade in and haul it out",
+                results: &[
+                    EventResult {
+                        weight: 55,
+                        text: "\x1b[33mThe rotten lid gives way to a handful of coins.\x1b[0m",
+                        outcomes: &[EventOutcome::Gold(60)],
+                    },
+                    EventResult {
+                        weight: 45,
+                        text: "\x1b[31mThe chest was bait. The bog rises to meet you!\x1b[0m",
+                        outcomes: &[EventOutcome::Ambush(EnemyKind::BogHorror)],
+                    },
+                ],
+            },
+            EventChoice {
+                text: "Leave it to the marsh",
+                results: &[NOTHING_HAPPENS],
+            },
+        ],
+    },
+];
+
+impl RandomEvent {
+    pub fn try_trigger(location: &Location, rng: &mut impl Rng) -> Option<&'static RandomEvent> {
         if rng.random_range(1..=100) > 80 {
-            let events = [
-                "\x1b[36m🌟 A shooting star streaks across the sky. You feel strangely invigorated.\x1b[0m",
-                "\x1b[90m🦅 A raven perches nearby, watching you with intelligent eyes before flying away.\x1b[0m",
-                "\x1b[35m👻 You hear distant laughter, though no one is there...\x1b[0m",
-                "\x1b[33m🍂 A cold wind blows, carrying whispers of forgotten names.\x1b[0m",
-                "\x1b[32m🌿 You notice strange mushrooms glowing faintly in the darkness.\x1b[0m",
-            ];
-            Some(events[rng.random_range(0..events.len())].to_string())
+            let events: Vec<&'static RandomEvent> = RANDOM_EVENTS
+                .iter()
+                .filter(|event| event.locations.is_empty() || event.locations.contains(location))
+                .collect();
+            Some(events[rng.random_range(0..events.len())])
         } else {
             None
         }
@@ -0,0 +1,74 @@
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::storage;
+use crate::unlock::Unlock;
+
+const PROFILE_FILE: &str = "emberlight_profile.json";
+
+// Everything that outlives a single run
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Profile {
+    #[serde(default)]
+    achievements: BTreeSet<String>,
+    #[serde(default)]
+    embers: u32,
+    #[serde(default)]
+    unlocks: BTreeSet<String>,
+    #[serde(default)]
+    last_daily: Option<String>, // Date of the last Daily Challenge attempted
+}
+
+impl Profile {
+    // A missing profile starts fresh; a damaged one is reported so it isn't saved over
+    pub fn load() -> io::Result<Self> {
+        storage::read_json(PROFILE_FILE)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let file = File::create(PROFILE_FILE)?;
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+
+    pub fn has_achievement(&self, id: &str) -> bool {
+        self.achievements.contains(id)
+    }
+
+    pub fn unlock_achievement(&mut self, id: &str) {
+        self.achievements.insert(id.to_string());
+    }
+
+    pub fn embers(&self) -> u32 {
+        self.embers
+    }
+
+    pub fn add_embers(&mut self, amount: u32) {
+        self.embers += amount;
+    }
+
+    pub fn has_unlock(&self, id: &str) -> bool {
+        self.unlocks.contains(id)
+    }
+
+    // Spends embers on an unlock; false if it is owned or unaffordable
+    pub fn buy(&mut self, unlock: &Unlock) -> bool {
+        if self.has_unlock(unlock.id) || self.embers < unlock.cost {
+            return false;
+        }
+        self.embers -= unlock.cost;
+        self.unlocks.insert(unlock.id.to_string());
+        true
+    }
+
+    pub fn has_attempted_daily(&self, date: &str) -> bool {
+        self.last_daily.as_deref() == Some(date)
+    }
+
+    pub fn mark_daily_attempt(&mut self, date: &str) {
+        self.last_daily = Some(date.to_string());
+    }
+}
@@ -0,0 +1,235 @@
+use rand::Rng;
+
+use crate::enemy::EnemyKind;
+use crate::event::GameEvent;
+use crate::narrative::{Location, LocationAction};
+
+const MAX_ACTIVE_QUESTS: usize = 3;
+const OFFER_CHANCE: u32 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Objective {
+    Slay {
+        kind: EnemyKind,
+        count: u32,
+    },
+    FindItem {
+        name: &'static str,
+    },
+    PerformAction {
+        action: LocationAction,
+        location: Location,
+    },
+}
+
+impl Objective {
+    fn required(&self) -> u32 {
+        match self {
+            Objective::Slay { count, .. } => *count,
+            Objective::FindItem { .. } | Objective::PerformAction { .. } => 1,
+        }
+    }
+
+    fn advanced_by(&self, event: &GameEvent) -> bool {
+        match (self, event) {
+            (Objective::Slay { kind, .. }, GameEvent::EnemySlain(slain)) => kind == slain,
+            (Objective::FindItem { name }, GameEvent::ItemFound(found)) => name == found,
+            (Objective::PerformAction { action, location }, GameEvent::ActionTaken(at, taken)) => {
+                location == at && action == taken
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reward {
+    Gold(u32),
+    Item(&'static str, u32),
+    Experience(u32),
+}
+
+impl Reward {
+    pub fn describe(&self) -> String {
+        match self {
+            Reward::Gold(amount) => format!("{} gold", amount),
+            Reward::Item(name, _) => name.to_string(),
+            Reward::Experience(amount) => format!("{} XP", amount),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct QuestDef {
+    pub title: &'static str,
+    pub giver: &'static str,
+    pub description: &'static str,
+    pub objective: Objective,
+    pub rewards: &'static [Reward],
+}
+
+pub const QUESTS: &[QuestDef] = &[
+    QuestDef {
+        title: "The Orc Menace",
+        giver: "A bloodstained notice flutters on a signpost.",
+        description: "Slay 3 Orcs",
+        objective: Objective::Slay {
+            kind: EnemyKind::Orc,
+            count: 3,
+        },
+        rewards: &[Reward::Gold(120), Reward::Experience(60)],
+    },
+    QuestDef {
+        title: "Bones in the Dark",
+        giver: "A trembling gravedigger grabs your arm as you pass.",
+        description: "Slay 3 Skeletons",
+        objective: Objective::Slay {
+            kind: EnemyKind::Skeleton,
+            count: 3,
+        },
+        rewards: &[Reward::Experience(100)],
+    },
+    QuestDef {
+        title: "Wolf Cull",
+        giver: "A shepherd, clutching a torn cloak, begs for your help.",
+        description: "Slay 2 Dire Wolves",
+        objective: Objective::Slay {
+            kind: EnemyKind::DireWolf,
+            count: 2,
+        },
+        rewards: &[Reward::Item("Health Potion", 50), Reward::Gold(60)],
+    },
+    QuestDef {
+        title: "Embers Below",
+        giver: "A scorched dwarf miner stumbles out of the smoke.",
+        description: "Slay 2 Fire Elementals",
+        objective: Objective::Slay {
+            kind: EnemyKind::FireElemental,
+            count: 2,
+        },
+        rewards: &[Reward::Gold(150), Reward::Experience(50)],
+    },
+    QuestDef {
+        title: "Scale of the Wyrm",
+        giver: "A hooded alchemist whispers of a wealthy buyer.",
+        description: "Find a Dragon Scale",
+        objective: Objective::FindItem {
+            name: "Dragon Scale",
+        },
+        rewards: &[Reward::Gold(200), Reward::Experience(80)],
+    },
+    QuestDef {
+        title: "Rites of the Forgotten",
+        giver: "The ghost of a priestess flickers at the roadside.",
+        description: "Pray at the Haunted Crypt",
+        objective: Objective::PerformAction {
+            action: LocationAction::Pray,
+            location: Location::HauntedCrypt,
+        },
+        rewards: &[Reward::Experience(70), Reward::Item("Energy Potion", 40)],
+    },
+    QuestDef {
+        title: "Lost Lore",
+        giver: "An old scholar asks you to copy a forgotten inscription.",
+        description: "Read the runes in the Abandoned Ruins",
+        objective: Objective::PerformAction {
+            action: LocationAction::ReadRunes,
+            location: Location::AbandonedRuins,
+        },
+        rewards: &[Reward::Gold(90), Reward::Experience(40)],
+    },
+];
+
+#[derive(Debug)]
+pub struct Quest {
+    def: &'static QuestDef,
+    progress: u32,
+}
+
+impl Quest {
+    pub fn title(&self) -> &str {
+        self.def.title
+    }
+
+    pub fn status(&self) -> String {
+        format!(
+            "{} ({}/{})",
+            self.def.description,
+            self.progress,
+            self.def.objective.required()
+        )
+    }
+
+    fn is_complete(&self) -> bool {
+        self.progress >= self.def.objective.required()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct QuestLog {
+    active: Vec<Quest>,
+    completed: Vec<Quest>,
+}
+
+impl QuestLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn active(&self) -> &[Quest] {
+        &self.active
+    }
+
+    pub fn completed(&self) -> &[Quest] {
+        &self.completed
+    }
+
+    pub fn knows(&self, def: &QuestDef) -> bool {
+        self.active
+            .iter()
+            .chain(self.completed.iter())
+            .any(|quest| quest.def == def)
+    }
+
+    // Occasionally a stranger or a notice along the road offers new work
+    pub fn roll_offer(&self, rng: &mut impl Rng) -> Option<&'static QuestDef> {
+        if self.active.len() >= MAX_ACTIVE_QUESTS || rng.random_range(1..=100) > OFFER_CHANCE {
+            return None;
+        }
+
+        let available: Vec<&'static QuestDef> =
+            QUESTS.iter().filter(|def| !self.knows(def)).collect();
+        if available.is_empty() {
+            None
+        } else {
+            Some(available[rng.random_range(0..available.len())])
+        }
+    }
+
+    // Takes on a quest unless the log is already full
+    pub fn accept(&mut self, def: &'static QuestDef) -> bool {
+        if self.active.len() >= MAX_ACTIVE_QUESTS {
+            return false;
+        }
+        self.active.push(Quest { def, progress: 0 });
+        true
+    }
+
+    // Advances matching objectives and returns the quests this event completed
+    pub fn record(&mut self, event: &GameEvent) -> Vec<&'static QuestDef> {
+        for quest in &mut self.active {
+            if quest.def.objective.advanced_by(event) {
+                quest.progress += 1;
+            }
+        }
+
+        let mut finished = Vec::new();
+        let (done, still_active) = self.active.drain(..).partition(Quest::is_complete);
+        self.active = still_active;
+        for quest in done {
+            finished.push(quest.def);
+            self.completed.push(quest);
+        }
+        finished
+    }
+}
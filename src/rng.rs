@@ -0,0 +1,55 @@
+use std::cell::RefCell;
+
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+const STREAM_SPREAD: u64 = 0x9E37_79B9_7F4A_7C15;
+
+thread_local! {
+    static GENERATOR: RefCell<StdRng> = RefCell::new(StdRng::seed_from_u64(rand::random()));
+}
+
+// Restarts the game's dice from a known seed so a run can be replayed
+pub fn seed(seed: u64) {
+    GENERATOR.with(|generator| *generator.borrow_mut() = StdRng::seed_from_u64(seed));
+}
+
+pub fn fresh_seed() -> u64 {
+    rand::random()
+}
+
+// The parts of an encounter that everyone playing a seed should meet alike
+#[derive(Debug, Clone, Copy)]
+pub enum Stream {
+    Spawns,
+    Events,
+    Loot,
+}
+
+// Like a dungeon floor, each stream depends only on the seed and the encounter,
+// so how many dice earlier battles rolled never changes the foes, roads or loot ahead
+pub fn stream(seed: u64, encounter: u32, stream: Stream) -> StdRng {
+    let index = encounter as u64 * 3 + stream as u64;
+    StdRng::seed_from_u64(seed ^ index.wrapping_mul(STREAM_SPREAD))
+}
+
+// Drop-in replacement for `rand::rng()` that draws from the seeded generator
+pub fn rng() -> GameRng {
+    GameRng
+}
+
+pub struct GameRng;
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        GENERATOR.with(|generator| generator.borrow_mut().next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        GENERATOR.with(|generator| generator.borrow_mut().next_u64())
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        GENERATOR.with(|generator| generator.borrow_mut().fill_bytes(dst))
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+
+use crate::character::Class;
+use crate::event::{CombatAction, GameEvent, GoldSource};
+
+// Everything worth tallying about a single run
+#[derive(Debug, Serialize)]
+pub struct RunStats {
+    pub name: String,
+    pub class: Class,
+    pub seed: u64,
+    pub damage_dealt: u32,
+    pub damage_taken: u32,
+    pub crits_landed: u32,
+    pub crits_received: u32,
+    pub abilities_used: BTreeMap<String, u32>,
+    pub potions_used: u32,
+    pub gold_earned: u32,
+    pub gold_found: u32,
+    pub enemies_killed: BTreeMap<String, u32>,
+    pub locations_visited: BTreeMap<String, u32>,
+    pub talents: Vec<String>, // Learned talent ids in order, ready for Talents::restore
+    #[serde(skip)]
+    special: String,
+}
+
+impl RunStats {
+    pub fn new(name: &str, class: Class, special: &str, seed: u64) -> Self {
+        RunStats {
+            name: name.to_string(),
+            class,
+            seed,
+            damage_dealt: 0,
+            damage_taken: 0,
+            crits_landed: 0,
+            crits_received: 0,
+            abilities_used: BTreeMap::new(),
+            potions_used: 0,
+            gold_earned: 0,
+            gold_found: 0,
+            enemies_killed: BTreeMap::new(),
+            locations_visited: BTreeMap::new(),
+            talents: Vec::new(),
+            special: special.to_string(),
+        }
+    }
+
+    pub fn record(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::LocationVisited(location) => {
+                *self
+                    .locations_visited
+                    .entry(location.name().to_string())
+                    .or_default() += 1;
+            }
+            GameEvent::CombatActionTaken(action) => {
+                let ability = match action {
+                    CombatAction::Attack => "Attack",
+                    CombatAction::Special => &self.special,
+                    CombatAction::Defend => "Defend",
+                    CombatAction::Item => "Item",
+                    CombatAction::Summon => "Summon",
+                };
+                *self.abilities_used.entry(ability.to_string()).or_default() += 1;
+            }
+            GameEvent::DamageDealt { amount, critical } => {
+                self.damage_dealt += amount;
+                if *critical {
+                    self.crits_landed += 1;
+                }
+            }
+            GameEvent::DamageTaken { amount, critical } => {
+                self.damage_taken += amount;
+                if *critical {
+                    self.crits_received += 1;
+                }
+            }
+            GameEvent::EnemySlain(kind) => {
+                *self
+                    .enemies_killed
+                    .entry(kind.name().to_string())
+                    .or_default() += 1;
+            }
+            _ if event.is_potion_used() => self.potions_used += 1,
+            GameEvent::TalentLearned(id) => self.talents.push(id.to_string()),
+            GameEvent::GoldGained { amount, source } => match source {
+                GoldSource::Earned => self.gold_earned += amount,
+                GoldSource::Found => self.gold_found += amount,
+            },
+            _ => {}
+        }
+    }
+
+    // Writes the breakdown next to the other save files; returns the file name.
+    // Seeds repeat across replays and daily runs, so the name and time keep exports apart
+    pub fn export(&self) -> io::Result<String> {
+        let hero: String = self
+            .name
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect();
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let stem = format!("emberlight_run_{}_{}_{}", self.seed, hero, timestamp);
+
+        let mut path = format!("{}.json", stem);
+        let mut copy = 1;
+        let file = loop {
+            match File::create_new(&path) {
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    copy += 1;
+                    path = format!("{}_{}.json", stem, copy);
+                }
+                result => break result?,
+            }
+        };
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(path)
+    }
+}
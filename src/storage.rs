@@ -0,0 +1,18 @@
+use std::fs;
+use std::io;
+
+use serde::de::DeserializeOwned;
+
+// Only a missing file counts as empty; a damaged one is reported so it is never overwritten
+pub fn read_json<T: Default + DeserializeOwned>(path: &str) -> io::Result<T> {
+    match fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is damaged: {}", path, err),
+            )
+        }),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(err),
+    }
+}
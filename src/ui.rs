@@ -1,5 +1,12 @@
+use crate::achievement::ACHIEVEMENTS;
+use crate::character::talent::Talents;
 use crate::character::Character;
+use crate::daily::DailyChallenge;
 use crate::enemy::Enemy;
+use crate::leaderboard::{Ranking, RunMode, RunRecord};
+use crate::profile::Profile;
+use crate::quest::QuestLog;
+use crate::stats::RunStats;
 
 pub fn print_banner() {
     println!("\n");
@@ -14,6 +21,36 @@ pub fn print_banner() {
     );
 }
 
+// Pads text to a column width, counting emoji as two columns and
+// skipping ANSI color codes, so box borders line up
+pub fn pad(text: &str, width: usize) -> String {
+    let mut columns = 0;
+    let mut in_escape = false;
+    let mut previous = ' ';
+    for c in text.chars() {
+        match c {
+            '\x1b' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if in_escape => {}
+            // The emoji selector widens a narrow symbol such as ⚔ into ⚔️
+            '\u{FE0F}' if char_width(previous) == 1 && previous >= '\u{2000}' => columns += 1,
+            '\u{FE0F}' => {}
+            _ => columns += char_width(c),
+        }
+        previous = c;
+    }
+    format!("{}{}", text, " ".repeat(width.saturating_sub(columns)))
+}
+
+fn char_width(c: char) -> usize {
+    match c {
+        '\u{1F000}'.. => 2,
+        // Symbols drawn as emoji even without a selector
+        '✅' | '✨' | '❌' | '⚡' | '⭐' | '⌛' | '⏳' | '⛔' | '❓' | '❗' => 2,
+        _ => 1,
+    }
+}
+
 pub fn print_divider() {
     println!("\n{}", "═".repeat(42));
 }
@@ -59,7 +96,153 @@ pub fn print_resource_bar(label: &str, current: u32, max: u32) {
 pub fn show_character_stats(character: &dyn Character, gold: u32, battles_won: u32) {
     println!("│  Name: {:<32} │", character.get_name());
     println!("│  Level: {:<31} │", character.get_level());
+    println!(
+        "│  XP: {:<34} │",
+        format!(
+            "{}/{}",
+            character.get_experience(),
+            character.experience_to_next_level()
+        )
+    );
     print_health_bar("HP", character.get_health(), character.get_max_health());
     println!("│  Gold: {:<32} │", gold);
     println!("│  Battles Won: {:<26} │", battles_won);
 }
+
+pub fn show_quest_log(quest_log: &QuestLog) {
+    println!("\n┌────────────────────────────────────────┐");
+    println!("│  📜 QUEST LOG                          │");
+    println!("├────────────────────────────────────────┤");
+    if quest_log.active().is_empty() {
+        println!("│  No active quests                      │");
+    }
+    for quest in quest_log.active() {
+        println!("│  ◆ {:<36}│", quest.title());
+        println!("│      {:<34}│", quest.status());
+    }
+    if !quest_log.completed().is_empty() {
+        println!("├────────────────────────────────────────┤");
+        for quest in quest_log.completed() {
+            println!("│  ✓ \x1b[90m{:<36}\x1b[0m│", quest.title());
+        }
+    }
+    println!("└────────────────────────────────────────┘");
+}
+
+pub fn show_hall_of_fame(runs: &[&RunRecord], ranking: Ranking, class: &str, mode: RunMode) {
+    const SHOWN: usize = 10;
+
+    println!("\n┌──────────────────────────────────────────────────────────────────────┐");
+    println!("│  🏆 HALL OF FAME · {:<50}│", mode.name());
+    println!("│  Ranked by: {:<12} Class: {:<37}│", ranking.name(), class);
+    println!("├──────────────────────────────────────────────────────────────────────┤");
+    if runs.is_empty() {
+        println!("│  {:<68}│", "No legends yet. Be the first.");
+    }
+    for (rank, run) in runs.iter().take(SHOWN).enumerate() {
+        println!(
+            "│  {:>2}. {:<12} {:<11} {:>3} {} {:>6}g  {:<22}│",
+            rank + 1,
+            run.name,
+            run.class.name(),
+            run.encounters,
+            mode.tally(),
+            run.gold,
+            run.cause
+        );
+        println!("│      \x1b[90mseed {:<59}\x1b[0m│", run.seed);
+        let talents = Talents::restore(run.class, &run.talents);
+        if talents.count() > 0 {
+            let mut names = format!("🌟 {}", talents.names().join(", "));
+            if names.chars().count() > 63 {
+                names = names.chars().take(60).collect::<String>() + "...";
+            }
+            println!("│      \x1b[90m{}\x1b[0m│", pad(&names, 64));
+        }
+    }
+    println!("└──────────────────────────────────────────────────────────────────────┘");
+}
+
+pub fn show_daily_challenge(challenge: &DailyChallenge, runs: &[&RunRecord]) {
+    const SHOWN: usize = 10;
+
+    println!("\n┌──────────────────────────────────────────────────────────────────────┐");
+    println!("│  📅 DAILY CHALLENGE {:<49}│", challenge.date());
+    println!("│  \x1b[90mseed {:<63}\x1b[0m│", challenge.seed());
+    println!("├──────────────────────────────────────────────────────────────────────┤");
+    for modifier in challenge.modifiers() {
+        println!(
+            "│  {} {:<65}│",
+            modifier.icon(),
+            format!("{} - {}", modifier.name(), modifier.description())
+        );
+    }
+    println!("├──────────────────────────────────────────────────────────────────────┤");
+    if runs.is_empty() {
+        println!("│  {:<68}│", "Nobody has braved today's challenge yet.");
+    }
+    for (rank, run) in runs.iter().take(SHOWN).enumerate() {
+        println!(
+            "│  {:>2}. {:<12} {:<11} {:>3} enc {:>6}g  {:<22}│",
+            rank + 1,
+            run.name,
+            run.class.name(),
+            run.encounters,
+            run.gold,
+            run.cause
+        );
+    }
+    println!("└──────────────────────────────────────────────────────────────────────┘");
+}
+
+pub fn show_achievements(profile: &Profile) {
+    let unlocked = ACHIEVEMENTS
+        .iter()
+        .filter(|achievement| profile.has_achievement(achievement.id))
+        .count();
+
+    println!("\n┌────────────────────────────────────────────────────────────┐");
+    println!("│  🏅 ACHIEVEMENTS                                           │");
+    println!(
+        "│  {:<58}│",
+        format!("{}/{} unlocked", unlocked, ACHIEVEMENTS.len())
+    );
+    println!("├────────────────────────────────────────────────────────────┤");
+    for achievement in ACHIEVEMENTS {
+        if profile.has_achievement(achievement.id) {
+            println!("│  🏅 \x1b[93m{:<55}\x1b[0m│", achievement.title);
+        } else {
+            println!("│  🔒 \x1b[90m{:<55}\x1b[0m│", achievement.title);
+        }
+        println!("│      {:<54}│", achievement.description);
+    }
+    println!("└────────────────────────────────────────────────────────────┘");
+}
+
+pub fn show_run_stats(stats: &RunStats) {
+    println!("\n┌────────────────────────────────────────┐");
+    println!("│  📊 RUN BREAKDOWN                      │");
+    println!("├────────────────────────────────────────┤");
+    println!("│  Damage Dealt: {:<24}│", stats.damage_dealt);
+    println!("│  Damage Taken: {:<24}│", stats.damage_taken);
+    println!("│  Crits Landed: {:<24}│", stats.crits_landed);
+    println!("│  Crits Received: {:<22}│", stats.crits_received);
+    println!("│  Potions Used: {:<24}│", stats.potions_used);
+    println!("│  Gold Earned: {:<25}│", stats.gold_earned);
+    println!("│  Gold Found: {:<26}│", stats.gold_found);
+    for (title, tally) in [
+        ("Abilities Used", &stats.abilities_used),
+        ("Enemies Slain", &stats.enemies_killed),
+        ("Places Visited", &stats.locations_visited),
+    ] {
+        println!("├────────────────────────────────────────┤");
+        println!("│  {:<38}│", title);
+        if tally.is_empty() {
+            println!("│    {:<36}│", "None");
+        }
+        for (name, count) in tally {
+            println!("│    {:<28} x{:<4}│", name, count);
+        }
+    }
+    println!("└────────────────────────────────────────┘");
+}
@@ -0,0 +1,122 @@
+use crate::character::{Class, Stat};
+use crate::narrative::Location;
+use crate::profile::Profile;
+
+// Embers a run earns for each encounter survived and each boss slain
+pub const EMBERS_PER_ENCOUNTER: u32 = 1;
+pub const EMBERS_PER_BOSS: u32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Perk {
+    StartingItem(&'static str, u32),
+    StartingGold(u32),
+    // A twist on a class that starts with extra training in one stat
+    Variant {
+        class: Class,
+        stat: Stat,
+        points: u32,
+    },
+    Location(Location),
+}
+
+// A permanent boon bought with embers between runs
+#[derive(Debug)]
+pub struct Unlock {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub cost: u32,
+    pub perk: Perk,
+}
+
+pub const UNLOCKS: &[Unlock] = &[
+    Unlock {
+        id: "field_medic",
+        name: "Field Medic",
+        description: "Start every run with an extra Health Potion",
+        cost: 10,
+        perk: Perk::StartingItem("Health Potion", 50),
+    },
+    Unlock {
+        id: "family_fortune",
+        name: "Family Fortune",
+        description: "Start every run with 50 more gold",
+        cost: 15,
+        perk: Perk::StartingGold(50),
+    },
+    Unlock {
+        id: "alchemist_kit",
+        name: "Alchemist's Kit",
+        description: "Start every run with a Fire Flask",
+        cost: 20,
+        perk: Perk::StartingItem("Fire Flask", 70),
+    },
+    Unlock {
+        id: "berserker",
+        name: "Berserker",
+        description: "Warrior variant with +3 Strength",
+        cost: 25,
+        perk: Perk::Variant {
+            class: Class::Warrior,
+            stat: Stat::Strength,
+            points: 3,
+        },
+    },
+    Unlock {
+        id: "battlemage",
+        name: "Battlemage",
+        description: "Mage variant with +4 Vitality",
+        cost: 25,
+        perk: Perk::Variant {
+            class: Class::Mage,
+            stat: Stat::Vitality,
+            points: 4,
+        },
+    },
+    Unlock {
+        id: "shadowblade",
+        name: "Shadowblade",
+        description: "Rogue variant with +3 Stealth",
+        cost: 25,
+        perk: Perk::Variant {
+            class: Class::Rogue,
+            stat: Stat::Stealth,
+            points: 3,
+        },
+    },
+    Unlock {
+        id: "sunken_temple",
+        name: "Sunken Temple",
+        description: "A drowned temple joins the roads near the marsh",
+        cost: 40,
+        perk: Perk::Location(Location::SunkenTemple),
+    },
+];
+
+impl Unlock {
+    // Unlocks the profile has already bought
+    pub fn owned(profile: &Profile) -> impl Iterator<Item = &'static Unlock> + '_ {
+        UNLOCKS
+            .iter()
+            .filter(|unlock| profile.has_unlock(unlock.id))
+    }
+
+    pub fn variants(profile: &Profile, class: Class) -> Vec<&'static Unlock> {
+        Unlock::owned(profile)
+            .filter(|unlock| matches!(unlock.perk, Perk::Variant { class: c, .. } if c == class))
+            .collect()
+    }
+
+    pub fn locations(profile: &Profile) -> Vec<Location> {
+        Unlock::owned(profile)
+            .filter_map(|unlock| match unlock.perk {
+                Perk::Location(location) => Some(location),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+pub fn embers_earned(encounters: u32, bosses: u32) -> u32 {
+    encounters * EMBERS_PER_ENCOUNTER + bosses * EMBERS_PER_BOSS
+}
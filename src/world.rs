@@ -0,0 +1,53 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::clock::Clock;
+
+// Counters
+pub const PRAYERS: &str = "prayers";
+pub const OMENS_SEEN: &str = "omens_seen";
+pub const BOSSES_SLAIN: &str = "bosses_slain";
+pub const BOSSES_SPARED: &str = "bosses_spared";
+
+// Flags
+pub const MAREN_AT_PEACE: &str = "maren_at_peace";
+
+// Everything the world remembers about the player's choices during a run
+#[derive(Debug, Default)]
+pub struct WorldState {
+    flags: HashSet<&'static str>,
+    counters: HashMap<&'static str, u32>,
+    clock: Clock,
+}
+
+impl WorldState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_flag(&mut self, flag: &'static str) {
+        self.flags.insert(flag);
+    }
+
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
+
+    // Bumps a counter and returns its new value
+    pub fn increment(&mut self, counter: &'static str) -> u32 {
+        let count = self.counters.entry(counter).or_insert(0);
+        *count += 1;
+        *count
+    }
+
+    pub fn count(&self, counter: &str) -> u32 {
+        self.counters.get(counter).copied().unwrap_or(0)
+    }
+
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    pub fn clock_mut(&mut self) -> &mut Clock {
+        &mut self.clock
+    }
+}